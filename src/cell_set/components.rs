@@ -0,0 +1,119 @@
+use crate::{error::ResolutionMismatch, grid, CellIndex};
+
+/// Partitions a set of cells into connected components.
+///
+/// Components are numbered from zero, in the order of their smallest cell.
+/// Duplicate cells are ignored.
+///
+/// # Errors
+///
+/// [`ResolutionMismatch`] if the cells don't have the same resolution.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cell_set, CellIndex};
+///
+/// let cells = [
+///     CellIndex::try_from(0x8a1fb46622dffff)?,
+///     CellIndex::try_from(0x8a1fb46622d7fff)?,
+///     CellIndex::try_from(0x8a1fb4644937fff)?,
+/// ];
+/// let components = cell_set::components(cells)?;
+/// assert_eq!(components.count(), 2);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn components(
+    cells: impl IntoIterator<Item = CellIndex>,
+) -> Result<Components, ResolutionMismatch> {
+    let mut cells = super::to_set(cells)?.into_iter().collect::<Vec<_>>();
+    cells.sort_unstable();
+
+    // Label propagation, using the sorted set for membership tests.
+    let mut labels = vec![usize::MAX; cells.len()];
+    let mut stack = Vec::new();
+    let mut count = 0;
+    for start in 0..cells.len() {
+        if labels[start] != usize::MAX {
+            continue;
+        }
+        labels[start] = count;
+        stack.push(start);
+        while let Some(position) = stack.pop() {
+            for neighbor in grid::neighbors(cells[position]) {
+                if let Ok(idx) = cells.binary_search(&neighbor) {
+                    if labels[idx] == usize::MAX {
+                        labels[idx] = count;
+                        stack.push(idx);
+                    }
+                }
+            }
+        }
+        count += 1;
+    }
+
+    Ok(Components {
+        cells,
+        labels,
+        count,
+    })
+}
+
+// -----------------------------------------------------------------------------
+
+/// Connected components of a set of cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    /// Sorted set of cells.
+    cells: Vec<CellIndex>,
+    /// Component ID of each cell.
+    labels: Vec<usize>,
+    /// Number of components.
+    count: usize,
+}
+
+impl Components {
+    /// Returns the number of components.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the component ID of the given cell, if it's part of the set.
+    #[must_use]
+    pub fn label(&self, cell: CellIndex) -> Option<usize> {
+        self.cells
+            .binary_search(&cell)
+            .ok()
+            .map(|idx| self.labels[idx])
+    }
+
+    /// Returns an iterator over the cells and their component ID.
+    pub fn iter(&self) -> impl Iterator<Item = (CellIndex, usize)> + '_ {
+        self.cells.iter().copied().zip(self.labels.iter().copied())
+    }
+
+    /// Returns the cells of the given component.
+    ///
+    /// The output can be directly turned into an outline with
+    /// [`ToGeo`](crate::geom::ToGeo).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{cell_set, geom::ToGeo, CellIndex};
+    ///
+    /// let cells = [
+    ///     CellIndex::try_from(0x8a1fb46622dffff)?,
+    ///     CellIndex::try_from(0x8a1fb46622d7fff)?,
+    ///     CellIndex::try_from(0x8a1fb4644937fff)?,
+    /// ];
+    /// let components = cell_set::components(cells)?;
+    /// let outline = components.component(0).to_geom(true)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn component(&self, id: usize) -> impl Iterator<Item = CellIndex> + '_ {
+        self.iter()
+            .filter_map(move |(cell, label)| (label == id).then_some(cell))
+    }
+}
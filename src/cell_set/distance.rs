@@ -0,0 +1,102 @@
+use crate::{error::ResolutionMismatch, grid, CellIndex};
+use ahash::HashMap;
+
+/// Assigns every cell to its nearest seed, in grid distance (i.e. computes a
+/// Voronoi partition of the grid, a.k.a. a distance transform).
+///
+/// Returns a list of `(cell, nearest_seed, distance)`, sorted by increasing
+/// distance then by cell. When several seeds are equally near, the smallest
+/// one (by index) is picked.
+///
+/// If a `region` is given, only the cells in the region are assigned and the
+/// distances are measured along paths that stay inside the region (seeds
+/// outside of the region are ignored). If `max_distance` is given, the cells
+/// farther away from every seed are left out.
+///
+/// You probably want to set a region and/or a maximum distance: without
+/// limits, the whole grid will be explored.
+///
+/// # Errors
+///
+/// [`ResolutionMismatch`] if the seeds and the region cells don't have the
+/// same resolution.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cell_set, CellIndex};
+///
+/// let seeds = [
+///     CellIndex::try_from(0x8a1fb46622dffff)?,
+///     CellIndex::try_from(0x8a1fb4644937fff)?,
+/// ];
+/// let cells = cell_set::nearest_seeds(seeds, None, Some(3))?;
+/// for (cell, seed, distance) in cells {
+///     assert!(distance <= 3);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn nearest_seeds(
+    seeds: impl IntoIterator<Item = CellIndex>,
+    region: Option<&[CellIndex]>,
+    max_distance: Option<u32>,
+) -> Result<Vec<(CellIndex, CellIndex, u32)>, ResolutionMismatch> {
+    let seeds = super::to_set(seeds)?;
+    let region = region
+        .map(|cells| super::to_set(cells.iter().copied()))
+        .transpose()?;
+    // Both sets must share the same resolution too.
+    let region_cell = region.as_ref().and_then(|region| region.iter().next());
+    if let (Some(seed), Some(cell)) = (seeds.iter().next(), region_cell) {
+        if seed.resolution() != cell.resolution() {
+            return Err(ResolutionMismatch);
+        }
+    }
+    let is_allowed = |cell: &CellIndex| {
+        region.as_ref().is_none_or(|region| region.contains(cell))
+    };
+    let max_distance = max_distance.unwrap_or(u32::MAX);
+
+    let mut seeds = seeds.into_iter().filter(is_allowed).collect::<Vec<_>>();
+    seeds.sort_unstable();
+
+    // Level-synchronous BFS: every cell at distance `d + 1` picks the smallest
+    // seed among its neighbors at distance `d`, which is also its smallest
+    // nearest seed.
+    let mut nearest = seeds
+        .iter()
+        .map(|&seed| (seed, (seed, 0)))
+        .collect::<HashMap<_, _>>();
+    let mut result = seeds
+        .iter()
+        .map(|&seed| (seed, seed, 0))
+        .collect::<Vec<_>>();
+    let mut frontier = seeds;
+    let mut distance = 0;
+    while !frontier.is_empty() && distance < max_distance {
+        distance += 1;
+        let mut next = Vec::new();
+        for cell in frontier {
+            let seed = nearest[&cell].0;
+            for neighbor in grid::neighbors(cell).filter(is_allowed) {
+                match nearest.get_mut(&neighbor) {
+                    None => {
+                        nearest.insert(neighbor, (seed, distance));
+                        next.push(neighbor);
+                    }
+                    Some(best) if best.1 == distance && seed < best.0 => {
+                        best.0 = seed;
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        next.sort_unstable();
+        result.extend(
+            next.iter().map(|&cell| (cell, nearest[&cell].0, distance)),
+        );
+        frontier = next;
+    }
+
+    Ok(result)
+}
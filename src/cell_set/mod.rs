@@ -0,0 +1,33 @@
+//! Analysis of sets of cells (at the same resolution).
+//!
+//! On a hexagonal grid, the three cells meeting at a vertex are all neighbors
+//! of each other (pentagons included), thus two cells sharing a vertex always
+//! share an edge: edge adjacency and vertex adjacency are the same thing.
+
+mod components;
+mod distance;
+mod morphology;
+
+pub use components::{components, Components};
+pub use distance::nearest_seeds;
+pub use morphology::{
+    boundary, close, dilate, erode, fill_holes, interior, open,
+};
+
+use crate::{error::ResolutionMismatch, CellIndex};
+use ahash::HashSet;
+
+/// Collects the cells into a set, checking that they share the same
+/// resolution.
+fn to_set(
+    cells: impl IntoIterator<Item = CellIndex>,
+) -> Result<HashSet<CellIndex>, ResolutionMismatch> {
+    let set = cells.into_iter().collect::<HashSet<_>>();
+    let mut resolutions = set.iter().map(|cell| cell.resolution());
+    if let Some(resolution) = resolutions.next() {
+        if resolutions.any(|res| res != resolution) {
+            return Err(ResolutionMismatch);
+        }
+    }
+    Ok(set)
+}
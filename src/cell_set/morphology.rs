@@ -0,0 +1,419 @@
+use super::to_set;
+use crate::{error::ResolutionMismatch, grid, CellIndex};
+use ahash::{HashMap, HashMapExt, HashSet};
+
+/// Grows a set of cells by `k` rings.
+///
+/// The output contains every cell within grid distance `k` of the input set,
+/// sorted and without duplicates.
+///
+/// # Errors
+///
+/// [`ResolutionMismatch`] if the cells don't have the same resolution.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cell_set, CellIndex};
+///
+/// let cell = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let cells = cell_set::dilate([cell], 2)?;
+/// assert_eq!(cells.len(), 19);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dilate(
+    cells: impl IntoIterator<Item = CellIndex>,
+    k: u32,
+) -> Result<Vec<CellIndex>, ResolutionMismatch> {
+    let mut set = to_set(cells)?;
+    dilate_set(&mut set, k);
+    Ok(into_sorted_vec(set))
+}
+
+/// Shrinks a set of cells by `k` rings.
+///
+/// The output contains every cell whose `k`-disk is fully inside the input
+/// set, sorted and without duplicates.
+///
+/// # Errors
+///
+/// [`ResolutionMismatch`] if the cells don't have the same resolution.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cell_set, CellIndex};
+///
+/// let cell = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let cells = cell_set::erode(cell.grid_disk::<Vec<_>>(2), 2)?;
+/// assert_eq!(cells, vec![cell]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn erode(
+    cells: impl IntoIterator<Item = CellIndex>,
+    k: u32,
+) -> Result<Vec<CellIndex>, ResolutionMismatch> {
+    let mut set = to_set(cells)?;
+    erode_set(&mut set, k);
+    Ok(into_sorted_vec(set))
+}
+
+/// Morphological opening: erosion followed by a dilation.
+///
+/// Removes the parts of the set that are thinner than `2k + 1` cells (e.g.
+/// isolated cells, narrow bridges).
+///
+/// # Errors
+///
+/// [`ResolutionMismatch`] if the cells don't have the same resolution.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cell_set, CellIndex};
+///
+/// let cell = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let cells = cell_set::open([cell], 1)?;
+/// assert!(cells.is_empty());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn open(
+    cells: impl IntoIterator<Item = CellIndex>,
+    k: u32,
+) -> Result<Vec<CellIndex>, ResolutionMismatch> {
+    let mut set = to_set(cells)?;
+    erode_set(&mut set, k);
+    dilate_set(&mut set, k);
+    Ok(into_sorted_vec(set))
+}
+
+/// Morphological closing: dilation followed by an erosion.
+///
+/// Fills the gaps of the set that are narrower than `2k + 1` cells (e.g. small
+/// holes, narrow channels).
+///
+/// # Errors
+///
+/// [`ResolutionMismatch`] if the cells don't have the same resolution.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cell_set, CellIndex};
+///
+/// let cell = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let ring = cell.grid_ring_fast(1).collect::<Option<Vec<_>>>()
+///     .unwrap_or_default();
+/// let cells = cell_set::close(ring, 1)?;
+/// assert_eq!(cells.len(), 7);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn close(
+    cells: impl IntoIterator<Item = CellIndex>,
+    k: u32,
+) -> Result<Vec<CellIndex>, ResolutionMismatch> {
+    let mut set = to_set(cells)?;
+    dilate_set(&mut set, k);
+    erode_set(&mut set, k);
+    Ok(into_sorted_vec(set))
+}
+
+/// Fills the holes of a set of cells.
+///
+/// A hole is a connected group of cells, outside of the set, that is fully
+/// enclosed by the set. Since the grid covers a sphere, the outside is defined
+/// as the largest group of cells not in the set.
+///
+/// # Errors
+///
+/// [`ResolutionMismatch`] if the cells don't have the same resolution.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cell_set, CellIndex};
+///
+/// let cell = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let ring = cell.grid_ring_fast(2).collect::<Option<Vec<_>>>()
+///     .unwrap_or_default();
+/// let cells = cell_set::fill_holes(ring)?;
+/// assert_eq!(cells.len(), 19);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn fill_holes(
+    cells: impl IntoIterator<Item = CellIndex>,
+) -> Result<Vec<CellIndex>, ResolutionMismatch> {
+    let mut set = to_set(cells)?;
+    let holes = Holes::new(&set).find();
+    set.extend(holes);
+    Ok(into_sorted_vec(set))
+}
+
+/// Returns the cells of the set that have at least one neighbor outside of
+/// the set.
+///
+/// # Errors
+///
+/// [`ResolutionMismatch`] if the cells don't have the same resolution.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cell_set, CellIndex};
+///
+/// let cell = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let cells = cell_set::boundary(cell.grid_disk::<Vec<_>>(2))?;
+/// assert_eq!(cells.len(), 12);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn boundary(
+    cells: impl IntoIterator<Item = CellIndex>,
+) -> Result<Vec<CellIndex>, ResolutionMismatch> {
+    let set = to_set(cells)?;
+    let mut cells = set
+        .iter()
+        .copied()
+        .filter(|&cell| is_boundary(&set, cell))
+        .collect::<Vec<_>>();
+    cells.sort_unstable();
+    Ok(cells)
+}
+
+/// Returns the cells of the set whose neighbors are all part of the set.
+///
+/// # Errors
+///
+/// [`ResolutionMismatch`] if the cells don't have the same resolution.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cell_set, CellIndex};
+///
+/// let cell = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let cells = cell_set::interior(cell.grid_disk::<Vec<_>>(2))?;
+/// assert_eq!(cells.len(), 7);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn interior(
+    cells: impl IntoIterator<Item = CellIndex>,
+) -> Result<Vec<CellIndex>, ResolutionMismatch> {
+    let set = to_set(cells)?;
+    let mut cells = set
+        .iter()
+        .copied()
+        .filter(|&cell| !is_boundary(&set, cell))
+        .collect::<Vec<_>>();
+    cells.sort_unstable();
+    Ok(cells)
+}
+
+// -----------------------------------------------------------------------------
+
+/// Returns true if the cell has a neighbor outside of the set.
+fn is_boundary(set: &HashSet<CellIndex>, cell: CellIndex) -> bool {
+    grid::neighbors(cell).any(|neighbor| !set.contains(&neighbor))
+}
+
+/// Converts the set into a sorted vector.
+fn into_sorted_vec(set: HashSet<CellIndex>) -> Vec<CellIndex> {
+    let mut cells = set.into_iter().collect::<Vec<_>>();
+    cells.sort_unstable();
+    cells
+}
+
+/// Grows the set by `k` rings, in place.
+///
+/// Only the outermost ring is expanded at each step, thus the cost is
+/// proportional to the number of added cells.
+fn dilate_set(set: &mut HashSet<CellIndex>, k: u32) {
+    let mut frontier = set
+        .iter()
+        .copied()
+        .filter(|&cell| is_boundary(set, cell))
+        .collect::<Vec<_>>();
+
+    for _ in 0..k {
+        let mut next = Vec::new();
+        for cell in frontier {
+            for neighbor in grid::neighbors(cell) {
+                if set.insert(neighbor) {
+                    next.push(neighbor);
+                }
+            }
+        }
+        frontier = next;
+    }
+}
+
+/// Shrinks the set by `k` rings, in place.
+///
+/// The set is peeled one ring at a time, starting from its boundary, thus the
+/// cost is proportional to the number of removed cells.
+fn erode_set(set: &mut HashSet<CellIndex>, k: u32) {
+    let mut frontier = set
+        .iter()
+        .copied()
+        .filter(|&cell| is_boundary(set, cell))
+        .collect::<Vec<_>>();
+
+    for step in 0..k {
+        for cell in &frontier {
+            set.remove(cell);
+        }
+        if step + 1 == k {
+            break;
+        }
+        // The next ring is made of the remaining neighbors of the removed one.
+        let mut next = frontier
+            .into_iter()
+            .flat_map(grid::neighbors)
+            .filter(|neighbor| set.contains(neighbor))
+            .collect::<Vec<_>>();
+        next.sort_unstable();
+        next.dedup();
+        frontier = next;
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Hole finder.
+///
+/// Every connected group of cells around the set is flooded in parallel, one
+/// ring at a time, until all but one are exhausted. The remaining one is then
+/// flooded until it's exhausted too or larger than the others: the largest
+/// group is the outside, the other ones are the holes.
+struct Holes<'a> {
+    /// Input set.
+    set: &'a HashSet<CellIndex>,
+    /// Flooded cells, with their region.
+    regions: HashMap<CellIndex, usize>,
+    /// Union-find forest, to merge the regions that meet.
+    parents: Vec<usize>,
+}
+
+impl<'a> Holes<'a> {
+    fn new(set: &'a HashSet<CellIndex>) -> Self {
+        Self {
+            set,
+            regions: HashMap::new(),
+            parents: Vec::new(),
+        }
+    }
+
+    /// Returns the cells in the holes.
+    fn find(mut self) -> Vec<CellIndex> {
+        // Seed a region on every cell touching the set.
+        let mut frontiers = HashMap::new();
+        for &cell in self.set {
+            for neighbor in grid::neighbors(cell) {
+                if self.set.contains(&neighbor)
+                    || self.regions.contains_key(&neighbor)
+                {
+                    continue;
+                }
+                let region = self.parents.len();
+                self.parents.push(region);
+                self.regions.insert(neighbor, region);
+                frontiers.insert(region, vec![neighbor]);
+            }
+        }
+
+        // Flood until at most one region is still growing.
+        let mut is_growing = true;
+        while is_growing {
+            let mut next = HashMap::<_, Vec<_>>::new();
+            for (region, frontier) in frontiers {
+                for cell in frontier {
+                    for neighbor in grid::neighbors(cell) {
+                        if self.set.contains(&neighbor) {
+                            continue;
+                        }
+                        if let Some(&other) = self.regions.get(&neighbor) {
+                            self.union(region, other);
+                            continue;
+                        }
+                        self.regions.insert(neighbor, region);
+                        next.entry(region).or_default().push(neighbor);
+                    }
+                }
+            }
+
+            // Group the frontiers by merged regions.
+            frontiers = HashMap::new();
+            for (region, cells) in next {
+                let root = self.find_root(region);
+                frontiers.entry(root).or_insert_with(Vec::new).extend(cells);
+            }
+            is_growing = frontiers.len() > 1;
+        }
+
+        // Resolve the region of every flooded cell.
+        let mut regions = std::mem::take(&mut self.regions)
+            .into_iter()
+            .map(|(cell, region)| (cell, self.find_root(region)))
+            .collect::<HashMap<_, _>>();
+        let mut sizes = HashMap::<_, usize>::new();
+        for &root in regions.values() {
+            *sizes.entry(root).or_default() += 1;
+        }
+
+        // The region still growing isn't necessarily the largest one: keep
+        // flooding it until it's exhausted or larger than every other region.
+        if let Some((root, mut frontier)) = frontiers.into_iter().next() {
+            let largest = sizes
+                .iter()
+                .filter_map(|(&other, &size)| (other != root).then_some(size))
+                .max()
+                .unwrap_or_default();
+            let mut size = sizes.get(&root).copied().unwrap_or_default();
+            while size <= largest && !frontier.is_empty() {
+                let mut next = Vec::new();
+                for cell in frontier {
+                    for neighbor in grid::neighbors(cell) {
+                        if self.set.contains(&neighbor)
+                            || regions.contains_key(&neighbor)
+                        {
+                            continue;
+                        }
+                        regions.insert(neighbor, root);
+                        next.push(neighbor);
+                    }
+                }
+                size += next.len();
+                frontier = next;
+            }
+            sizes.insert(root, size);
+        }
+
+        // The outside is the largest region.
+        let outside = sizes
+            .iter()
+            .max_by_key(|&(&root, &size)| (size, std::cmp::Reverse(root)))
+            .map(|(&root, _)| root);
+
+        regions
+            .into_iter()
+            .filter_map(|(cell, root)| (Some(root) != outside).then_some(cell))
+            .collect()
+    }
+
+    /// Returns the root of the region.
+    fn find_root(&mut self, mut region: usize) -> usize {
+        while self.parents[region] != region {
+            self.parents[region] = self.parents[self.parents[region]];
+            region = self.parents[region];
+        }
+        region
+    }
+
+    /// Merges two regions.
+    fn union(&mut self, a: usize, b: usize) {
+        let a = self.find_root(a);
+        let b = self.find_root(b);
+        if a != b {
+            self.parents[a.max(b)] = a.min(b);
+        }
+    }
+}
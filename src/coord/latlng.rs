@@ -3,8 +3,8 @@ use super::{
     RES0_U_GNOMONIC, SQRT7_POWERS,
 };
 use crate::{
-    error::InvalidLatLng, face, CellIndex, Face, Resolution, EARTH_RADIUS_KM,
-    TWO_PI,
+    error::InvalidLatLng, face, grid, CellIndex, DirectedEdgeIndex, Face,
+    Resolution, EARTH_RADIUS_KM, TWO_PI,
 };
 use float_eq::float_eq;
 use std::{
@@ -213,6 +213,50 @@ impl LatLng {
         self.to_face_ijk(resolution).to_cell(resolution)
     }
 
+    /// Returns, in order, the directed edges crossed by the great circle arc
+    /// (the shortest one) between the two coordinates.
+    ///
+    /// The path starts from `self.to_cell(resolution)` and ends at
+    /// `other.to_cell(resolution)`, every edge's origin is the destination of
+    /// the previous one. Pentagons are handled like any other cell.
+    ///
+    /// Between antipodal coordinates, the arc follows the meridian of `self`
+    /// (or the prime meridian, from a pole).
+    ///
+    /// Where rounding errors prevent following the arc exactly (e.g. near the
+    /// vertices at fine resolutions), the path is completed with the shortest
+    /// path on the grid, so that it always reaches the destination cell.
+    ///
+    /// When the arc goes exactly through a vertex, the edge leading to the cell
+    /// where the arc continues is picked (or, when the arc follows an edge, the
+    /// smallest edge index).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{LatLng, Resolution};
+    ///
+    /// let src = LatLng::new(48.864716, 2.349014)?;
+    /// let dst = LatLng::new(48.858093, 2.294694)?;
+    /// let edges = src.crossed_edges(dst, Resolution::Nine);
+    /// assert_eq!(
+    ///     edges.last().map(|edge| edge.destination()),
+    ///     Some(dst.to_cell(Resolution::Nine))
+    /// );
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub fn crossed_edges(
+        self,
+        other: Self,
+        resolution: Resolution,
+    ) -> Vec<DirectedEdgeIndex> {
+        grid::crossings(self, other, resolution)
+            .into_iter()
+            .map(|crossing| crossing.edge)
+            .collect()
+    }
+
     /// Encodes a coordinate on the sphere to the `FaceIJK` address of the
     /// containing cell at the specified resolution.
     ///
@@ -23,6 +23,26 @@ impl Vec3d {
 
         x_diff.mul_add(x_diff, y_diff.mul_add(y_diff, z_diff * z_diff))
     }
+
+    /// Computes the dot product of two 3D vectors.
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.x
+            .mul_add(other.x, self.y.mul_add(other.y, self.z * other.z))
+    }
+
+    /// Computes the cross product of two 3D vectors.
+    pub fn cross(&self, other: &Self) -> Self {
+        Self::new(
+            self.y.mul_add(other.z, -self.z * other.y),
+            self.z.mul_add(other.x, -self.x * other.z),
+            self.x.mul_add(other.y, -self.y * other.x),
+        )
+    }
+
+    /// Computes the length of the vector.
+    pub fn norm(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
 }
 
 #[cfg(test)]
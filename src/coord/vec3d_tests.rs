@@ -40,3 +40,22 @@ fn distance() {
         "distance to <1,1,2> is 6"
     );
 }
+
+#[test]
+fn dot() {
+    let v1 = Vec3d::new(1., 2., 3.);
+    let v2 = Vec3d::new(4., -5., 6.);
+
+    assert_float_eq!(v1.dot(&v2), 12., abs <= f64::EPSILON);
+    assert_float_eq!(v1.dot(&v1), 14., abs <= f64::EPSILON);
+}
+
+#[test]
+fn cross() {
+    let x = Vec3d::new(1., 0., 0.);
+    let y = Vec3d::new(0., 1., 0.);
+
+    assert_eq!(x.cross(&y), Vec3d::new(0., 0., 1.), "x × y = z");
+    assert_eq!(y.cross(&x), Vec3d::new(0., 0., -1.), "y × x = -z");
+    assert_float_eq!(x.cross(&x).norm(), 0., abs <= f64::EPSILON);
+}
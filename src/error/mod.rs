@@ -10,6 +10,8 @@ mod resolution_mismatch;
 mod geometry;
 #[cfg(feature = "geo")]
 mod outliner;
+#[cfg(feature = "geo")]
+mod too_many_cells;
 
 #[cfg(test)]
 mod tests;
@@ -28,3 +30,5 @@ pub use resolution_mismatch::ResolutionMismatch;
 pub use geometry::InvalidGeometry;
 #[cfg(feature = "geo")]
 pub use outliner::OutlinerError;
+#[cfg(feature = "geo")]
+pub use too_many_cells::TooManyCells;
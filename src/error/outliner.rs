@@ -4,7 +4,8 @@ use std::{error::Error, fmt};
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum OutlinerError {
-    /// Input contains indices of heterogeneous resolutions.
+    /// Input contains overlapping indices of heterogeneous resolutions (i.e.
+    /// a cell and one of its descendants).
     HeterogeneousResolution,
     /// Input set contains duplicate indices.
     DuplicateInput,
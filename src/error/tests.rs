@@ -5,7 +5,7 @@ use crate::error::{
     LocalIjError, ResolutionMismatch,
 };
 #[cfg(feature = "geo")]
-use crate::error::{InvalidGeometry, OutlinerError};
+use crate::error::{InvalidGeometry, OutlinerError, TooManyCells};
 use std::error::Error;
 
 // All error must have a non-empty display.
@@ -54,6 +54,9 @@ fn display() {
         .is_empty());
     #[cfg(feature = "geo")]
     assert!(!OutlinerError::DuplicateInput.to_string().is_empty());
+
+    #[cfg(feature = "geo")]
+    assert!(!TooManyCells::new(42, 10).to_string().is_empty());
 }
 
 // All errors are root errors.
@@ -92,4 +95,7 @@ fn source() {
     assert!(OutlinerError::HeterogeneousResolution.source().is_none());
     #[cfg(feature = "geo")]
     assert!(OutlinerError::DuplicateInput.source().is_none());
+
+    #[cfg(feature = "geo")]
+    assert!(TooManyCells::new(42, 10).source().is_none());
 }
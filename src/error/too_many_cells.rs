@@ -0,0 +1,45 @@
+use std::{error::Error, fmt};
+
+/// The coverage of a geometry would exceed the allowed number of cells.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TooManyCells {
+    count: usize,
+    limit: usize,
+}
+
+impl TooManyCells {
+    /// Initializes a new [`TooManyCells`] with the estimated cell count and
+    /// the allowed maximum.
+    pub(crate) const fn new(count: usize, limit: usize) -> Self {
+        Self { count, limit }
+    }
+
+    /// Returns the estimated number of cells (see
+    /// [`crate::geom::ToCells::max_cells_count`]).
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the maximum number of cells allowed.
+    #[must_use]
+    pub const fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+impl fmt::Display for TooManyCells {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "too many cells: up to {} cells, limit is {}",
+            self.count, self.limit
+        )
+    }
+}
+
+impl Error for TooManyCells {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
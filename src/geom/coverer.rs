@@ -0,0 +1,350 @@
+use super::{
+    geometry::{Class, Region},
+    ToCells,
+};
+use crate::{CellIndex, Resolution};
+use ahash::HashSet;
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+/// Geometries that can be approximated by a [`Coverer`].
+///
+/// This trait is implemented by every geometry of this module, and can't be
+/// implemented outside of this crate.
+pub trait Coverable: ToCells + Regions {}
+
+/// Geometries that can be split into regions.
+///
+/// Not exported outside of the crate, which seals [`Coverable`].
+pub trait Regions {
+    /// Returns the parts of the geometry, ready to classify cells for the
+    /// given maximum resolution.
+    fn regions(&self, resolution: Resolution) -> Vec<Region<'_>>;
+}
+
+/// Approximates geometries with a bounded number of cells, of mixed
+/// resolutions (similar to the `RegionCoverer` of S2).
+///
+/// The cells are classified against the geometry from the coarsest to the
+/// finest, starting from those at the minimum resolution: a cell is either
+/// inside, outside or on the boundary of the geometry, and only the cells on
+/// the boundary are refined, as fine as the budget allows.
+///
+/// A cell stands for all its descendants at the maximum resolution: a covering
+/// contains every cell of the geometry, an interior covering only contains
+/// cells of the geometry (as returned by [`ToCells`], lines being considered
+/// as straight in the longitude/latitude space).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Coverer {
+    /// Coarsest resolution of the output cells.
+    min_resolution: Resolution,
+    /// Finest resolution of the output cells.
+    max_resolution: Resolution,
+    /// Maximum number of output cells.
+    max_cells: usize,
+}
+
+impl Coverer {
+    /// Initializes a new coverer, returning cells between `min_resolution` and
+    /// `max_resolution` (inclusive), and at most `max_cells` cells.
+    ///
+    /// The resolutions are swapped if `min_resolution` is finer than
+    /// `max_resolution`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::Coverer, Resolution};
+    ///
+    /// let coverer = Coverer::new(Resolution::Four, Resolution::Nine, 8);
+    /// ```
+    #[must_use]
+    pub fn new(
+        min_resolution: Resolution,
+        max_resolution: Resolution,
+        max_cells: usize,
+    ) -> Self {
+        Self {
+            min_resolution: min_resolution.min(max_resolution),
+            max_resolution: min_resolution.max(max_resolution),
+            max_cells,
+        }
+    }
+
+    /// Returns a sorted set of cells covering the geometry.
+    ///
+    /// The covering is refined, from the minimum resolution, by splitting the
+    /// boundary cells that waste the most area first, as long as the budget
+    /// allows.
+    ///
+    /// The budget is exceeded if the geometry spans more than `max_cells`
+    /// cells at the minimum resolution. Cells too close to the geometry to be
+    /// told apart at their resolution are kept when the budget doesn't allow
+    /// refining them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::{Coverer, Polygon}, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 2.2, y: 48.8), (x: 2.4, y: 48.8), (x: 2.4, y: 48.9),
+    ///     (x: 2.2, y: 48.9), (x: 2.2, y: 48.8),
+    /// ];
+    /// let polygon = Polygon::from_degrees(p)?;
+    /// let coverer = Coverer::new(Resolution::Zero, Resolution::Nine, 16);
+    /// let cells = coverer.covering(&polygon);
+    /// assert!(cells.len() <= 16);
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn covering(&self, geometry: &impl Coverable) -> Vec<CellIndex> {
+        let regions = geometry.regions(self.max_resolution);
+        let cells = self.initial_cells(&regions);
+
+        let mut covering = HashSet::default();
+        let mut candidates = BinaryHeap::new();
+        for (cell, label) in cells {
+            covering.insert(cell);
+            candidates.extend(self.candidate(&regions, cell, &label, false));
+        }
+
+        let mut count = covering.len();
+        while let Some(candidate) = candidates.pop() {
+            // Splits that don't add cells are always worth it.
+            let new_count = count - 1 + candidate.children.len();
+            if new_count > self.max_cells && new_count > count {
+                continue;
+            }
+            count = new_count;
+            covering.remove(&candidate.cell);
+            for (child, label) in candidate.children {
+                covering.insert(child);
+                candidates
+                    .extend(self.candidate(&regions, child, &label, false));
+            }
+        }
+
+        let mut covering = covering.into_iter().collect::<Vec<_>>();
+        covering.sort_unstable();
+        covering
+    }
+
+    /// Returns a sorted set of cells contained in the geometry.
+    ///
+    /// The interior is refined, from the minimum resolution, by splitting the
+    /// boundary cells that may hold the most area first, as long as the
+    /// budget allows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::{Coverer, Polygon}, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 2.2, y: 48.8), (x: 2.4, y: 48.8), (x: 2.4, y: 48.9),
+    ///     (x: 2.2, y: 48.9), (x: 2.2, y: 48.8),
+    /// ];
+    /// let polygon = Polygon::from_degrees(p)?;
+    /// let coverer = Coverer::new(Resolution::Zero, Resolution::Nine, 16);
+    /// let cells = coverer.interior_covering(&polygon);
+    /// assert!(cells.len() <= 16);
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn interior_covering(
+        &self,
+        geometry: &impl Coverable,
+    ) -> Vec<CellIndex> {
+        let regions = geometry.regions(self.max_resolution);
+        let mut cells = self.initial_cells(&regions);
+
+        // Cells at the minimum resolution can't be split: keep the largest.
+        cells.sort_unstable_by(|&(a, _), &(b, _)| {
+            b.area_rads2().total_cmp(&a.area_rads2()).then(a.cmp(&b))
+        });
+        let mut interior = Vec::new();
+        let mut candidates = BinaryHeap::new();
+        for (cell, label) in cells {
+            if label == Label::Inside {
+                if interior.len() < self.max_cells {
+                    interior.push(cell);
+                }
+            } else {
+                candidates.extend(self.candidate(&regions, cell, &label, true));
+            }
+        }
+
+        while let Some(candidate) = candidates.pop() {
+            let inside = candidate
+                .children
+                .iter()
+                .filter(|child| child.1 == Label::Inside)
+                .count();
+            if interior.len() + inside > self.max_cells {
+                continue;
+            }
+            for (child, label) in candidate.children {
+                if label == Label::Inside {
+                    interior.push(child);
+                } else {
+                    candidates
+                        .extend(self.candidate(&regions, child, &label, true));
+                }
+            }
+        }
+
+        interior.sort_unstable();
+        interior
+    }
+
+    /// Returns the cells, at the minimum resolution, that are not outside the
+    /// geometry.
+    ///
+    /// The base cells are refined down to the minimum resolution, skipping
+    /// the cells outside of the geometry.
+    fn initial_cells(&self, regions: &[Region<'_>]) -> Vec<(CellIndex, Label)> {
+        let parts = regions
+            .iter()
+            .enumerate()
+            .map(|(index, region)| (index, region.edges()))
+            .collect::<Vec<_>>();
+        let mut cells = CellIndex::base_cells()
+            .map(|cell| (cell, classify(regions, cell, &parts)))
+            .collect::<Vec<_>>();
+
+        let mut initial = Vec::new();
+        while let Some((cell, label)) = cells.pop() {
+            match label {
+                Label::Outside => {}
+                _ if cell.resolution() == self.min_resolution => {
+                    initial.push((cell, label));
+                }
+                Label::Inside => initial.extend(
+                    cell.children(self.min_resolution)
+                        .map(|child| (child, Label::Inside)),
+                ),
+                Label::Boundary(ref parts) => cells.extend(
+                    children(cell)
+                        .map(|child| (child, classify(regions, child, parts))),
+                ),
+            }
+        }
+        initial
+    }
+
+    /// Returns the candidate for the split of the cell, if it's on the
+    /// boundary.
+    ///
+    /// For a covering, the gain is the number of cells, at the maximum
+    /// resolution, removed by the split. For an interior, it's the number
+    /// of those that may end up in the interior.
+    fn candidate(
+        &self,
+        regions: &[Region<'_>],
+        cell: CellIndex,
+        label: &Label,
+        is_interior: bool,
+    ) -> Option<Candidate> {
+        let Label::Boundary(ref parts) = *label else {
+            return None;
+        };
+        if cell.resolution() == self.max_resolution {
+            return None;
+        }
+
+        let mut gain = 0;
+        let mut kept = Vec::new();
+        for child in children(cell) {
+            let label = classify(regions, child, parts);
+            if (label == Label::Outside) != is_interior {
+                gain += child.children_count(self.max_resolution);
+            }
+            if label != Label::Outside {
+                kept.push((child, label));
+            }
+        }
+
+        Some(Candidate {
+            gain,
+            cell,
+            children: kept,
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Position of a cell relative to a geometry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Label {
+    /// Every descendant is in the geometry.
+    Inside,
+    /// No descendant is in the geometry.
+    Outside,
+    /// The descendants may be on both sides, holds the parts of the geometry
+    /// (and their edges) that are close to the cell.
+    Boundary(Vec<(usize, Vec<usize>)>),
+}
+
+/// Classifies the cell against the given parts of the geometry.
+///
+/// The parts must include every part close to the cell (e.g. those returned
+/// for its parent).
+fn classify(
+    regions: &[Region<'_>],
+    cell: CellIndex,
+    parts: &[(usize, Vec<usize>)],
+) -> Label {
+    let mut close = Vec::new();
+    for &(index, ref edges) in parts {
+        match regions[index].classify(cell, edges) {
+            Class::Inside => return Label::Inside,
+            Class::Outside => {}
+            Class::Boundary(edges) => close.push((index, edges)),
+        }
+    }
+
+    if close.is_empty() {
+        Label::Outside
+    } else {
+        Label::Boundary(close)
+    }
+}
+
+/// Returns the children of the cell, at the next resolution.
+fn children(cell: CellIndex) -> impl Iterator<Item = CellIndex> {
+    cell.resolution()
+        .succ()
+        .into_iter()
+        .flat_map(move |resolution| cell.children(resolution))
+}
+
+/// A cell that could be replaced by its children.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Candidate {
+    /// Number of cells, at the maximum resolution, gained by the split.
+    gain: u64,
+    /// The cell to split.
+    cell: CellIndex,
+    /// The children of the cell that are (partly) in the geometry.
+    children: Vec<(CellIndex, Label)>,
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Biggest gain first, then coarsest and cheapest split.
+        self.gain
+            .cmp(&other.gain)
+            .then_with(|| other.cell.resolution().cmp(&self.cell.resolution()))
+            .then_with(|| other.children.len().cmp(&self.children.len()))
+            .then_with(|| other.cell.cmp(&self.cell))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
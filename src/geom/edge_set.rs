@@ -0,0 +1,108 @@
+use crate::{DirectedEdgeIndex, LatLng};
+use std::collections::BTreeMap;
+
+/// A set of directed edges, outlined as polylines.
+///
+/// Contiguous edges (i.e. where an edge ends where the next one starts) are
+/// chained into a single polyline, following their direction.
+///
+/// [`ToGeo`](super::ToGeo) is implemented on this wrapper rather than on every
+/// `IntoIterator<Item = DirectedEdgeIndex>`, because such a blanket impl would
+/// conflict with the existing one on `IntoIterator<Item = CellIndex>`: wrap
+/// your edges (e.g. with `collect` or [`EdgeSet::new`]) to outline them.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{geom::{EdgeSet, ToGeo}, CellIndex};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let lines = index.edges().collect::<EdgeSet>().to_geom(true)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct EdgeSet(Vec<DirectedEdgeIndex>);
+
+impl EdgeSet {
+    /// Initializes a new set from the given edges.
+    ///
+    /// Duplicate edges are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::EdgeSet, CellIndex};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let edges = EdgeSet::new(index.edges());
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn new(edges: impl IntoIterator<Item = DirectedEdgeIndex>) -> Self {
+        let mut edges = edges.into_iter().collect::<Vec<_>>();
+        edges.sort_unstable();
+        edges.dedup();
+        Self(edges)
+    }
+
+    /// Chains the edges into polylines.
+    ///
+    /// Polylines are broken at the vertices where edges branch or merge.
+    /// Closed loops have the same first and last vertices.
+    pub(super) fn polylines(&self) -> Vec<Vec<LatLng>> {
+        let paths = self
+            .0
+            .iter()
+            .map(|edge| edge.boundary().to_vec())
+            .collect::<Vec<_>>();
+        let mut outgoing = BTreeMap::<_, Vec<_>>::new();
+        let mut incoming = BTreeMap::<_, usize>::new();
+        for (idx, path) in paths.iter().enumerate() {
+            outgoing.entry(path[0]).or_default().push(idx);
+            *incoming.entry(path[path.len() - 1]).or_default() += 1;
+        }
+        // A polyline goes through a vertex only if there is no branching.
+        let is_through = |vertex: &LatLng| {
+            incoming.get(vertex) == Some(&1)
+                && outgoing.get(vertex).is_some_and(|edges| edges.len() == 1)
+        };
+
+        let mut is_used = vec![false; paths.len()];
+        let mut polylines = Vec::new();
+        let mut chain = |start: usize, is_used: &mut Vec<bool>| {
+            let mut polyline = paths[start].clone();
+            is_used[start] = true;
+            let mut vertex = polyline[polyline.len() - 1];
+            while is_through(&vertex) {
+                let next = outgoing[&vertex][0];
+                if is_used[next] {
+                    break;
+                }
+                is_used[next] = true;
+                polyline.extend_from_slice(&paths[next][1..]);
+                vertex = polyline[polyline.len() - 1];
+            }
+            polylines.push(polyline);
+        };
+
+        // Open polylines first, starting where a polyline can't go through.
+        for idx in 0..paths.len() {
+            if !is_used[idx] && !is_through(&paths[idx][0]) {
+                chain(idx, &mut is_used);
+            }
+        }
+        // Then the remaining edges form closed loops.
+        for idx in 0..paths.len() {
+            if !is_used[idx] {
+                chain(idx, &mut is_used);
+            }
+        }
+
+        polylines
+    }
+}
+
+impl FromIterator<DirectedEdgeIndex> for EdgeSet {
+    fn from_iter<T: IntoIterator<Item = DirectedEdgeIndex>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
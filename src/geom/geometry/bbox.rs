@@ -53,33 +53,36 @@ pub fn compute_from_ring(
     ))
 }
 
+/// Lower bound of the cell areas, per resolution.
+///
+/// Area of a regular hexagon is `3/2*sqrt(3) * r * r`.
+///
+/// The pentagon has the most distortion (smallest edges) and shares its
+/// edges with hexagons, so the most-distorted hexagons have this area,
+/// shrunk by 20% off chance that the bounding box perfectly bounds a
+/// pentagon.
+pub const PENT_AREA_RADS2: [f64; 16] = [
+    0.05505118472518226,
+    0.006358420186890303,
+    0.0009676234334810151,
+    0.00012132336301389888,
+    0.000019309418286620768,
+    0.0000024521770265310696,
+    0.0000003928026439666205,
+    0.00000004997535264470275,
+    0.000000008012690511075445,
+    0.0000000010197039091132572,
+    0.00000000016351353999538285,
+    0.000000000020809697203105007,
+    0.000000000003336979666606075,
+    0.0000000000004246859893033221,
+    0.00000000000006810153522091642,
+    0.000000000000008667056198238203,
+];
+
 /// Returns an estimated number of hexagons that fit within the
 /// cartesian-projected bounding box.
 pub fn hex_estimate(bbox: &Rect, resolution: Resolution) -> usize {
-    // Area of a regular hexagon is `3/2*sqrt(3) * r * r`.
-    //
-    // The pentagon has the most distortion (smallest edges) and shares its
-    // edges with hexagons, so the most-distorted hexagons have this area,
-    // shrunk by 20% off chance that the bounding box perfectly bounds a
-    // pentagon.
-    const PENT_AREA_RADS2: [f64; 16] = [
-        0.05505118472518226,
-        0.006358420186890303,
-        0.0009676234334810151,
-        0.00012132336301389888,
-        0.000019309418286620768,
-        0.0000024521770265310696,
-        0.0000003928026439666205,
-        0.00000004997535264470275,
-        0.000000008012690511075445,
-        0.0000000010197039091132572,
-        0.00000000016351353999538285,
-        0.000000000020809697203105007,
-        0.000000000003336979666606075,
-        0.0000000000004246859893033221,
-        0.00000000000006810153522091642,
-        0.000000000000008667056198238203,
-    ];
     let pentagon_area_rads2 = PENT_AREA_RADS2[usize::from(resolution)];
 
     let min = bbox.min();
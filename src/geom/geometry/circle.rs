@@ -0,0 +1,156 @@
+use super::{
+    bbox::PENT_AREA_RADS2,
+    polyfill::{self, Class, Region},
+};
+use crate::{
+    error::InvalidGeometry,
+    geom::{Coverable, Regions, ToCells},
+    grid, CellIndex, LatLng, Resolution, EARTH_RADIUS_KM,
+};
+use ahash::HashSet;
+use std::{boxed::Box, f64::consts::PI};
+
+/// A circle on the sphere (a.k.a. spherical cap), made of the points within a
+/// given great circle distance of its center.
+///
+/// Unlike a polygonal approximation, it works the same everywhere (including
+/// across the antimeridian and around the poles).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Circle {
+    /// Center of the circle.
+    center: LatLng,
+    /// Radius, in meters.
+    radius: f64,
+}
+
+impl Circle {
+    /// Initialize a new circle from its center and its radius, in meters.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidGeometry`] if the radius isn't a finite non-negative number (a
+    /// zero radius is accepted).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::Circle, LatLng};
+    ///
+    /// let center = LatLng::new(48.864716, 2.349014)?;
+    /// let circle = Circle::new(center, 500.)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(center: LatLng, radius: f64) -> Result<Self, InvalidGeometry> {
+        if !radius.is_finite() || radius < 0. {
+            return Err(InvalidGeometry::new(
+                "radius must be a finite non-negative number",
+            ));
+        }
+        Ok(Self { center, radius })
+    }
+
+    /// Returns the center of the circle.
+    #[must_use]
+    pub const fn center(&self) -> LatLng {
+        self.center
+    }
+
+    /// Returns the radius of the circle, in meters.
+    #[must_use]
+    pub const fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Returns true if the point is inside the circle (boundary included).
+    fn contains(&self, point: LatLng) -> bool {
+        self.center.distance_m(point) <= self.radius
+    }
+
+    /// Classifies the descendants, at the given resolution, of the cell
+    /// according to the position of their center.
+    pub(super) fn classify(
+        &self,
+        cell: CellIndex,
+        resolution: Resolution,
+    ) -> Class {
+        let center = LatLng::from(cell);
+        let side = |is_inside| {
+            if is_inside {
+                Class::Inside
+            } else {
+                Class::Outside
+            }
+        };
+        if cell.resolution() == resolution {
+            return side(self.contains(center));
+        }
+
+        let radius = polyfill::radius(cell.resolution(), resolution, false)
+            * EARTH_RADIUS_KM
+            * 1000.;
+        let distance = self.center.distance_m(center);
+        if distance + radius <= self.radius || distance - radius > self.radius {
+            side(distance <= self.radius)
+        } else {
+            Class::Boundary(Vec::new())
+        }
+    }
+}
+
+impl ToCells for Circle {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // Finite and positive estimate, truncated on purpose.
+    fn max_cells_count(&self, resolution: Resolution) -> usize {
+        // Every cell whose center is inside the circle is contained in the
+        // circle grown by the cell radius (less than two edges).
+        let margin = 2. * resolution.edge_length_m();
+        let angle =
+            ((self.radius + margin) / (EARTH_RADIUS_KM * 1000.)).min(PI);
+        let area = 2. * PI * (1. - angle.cos());
+        let estimate = (area / PENT_AREA_RADS2[usize::from(resolution)]).ceil();
+        let estimate = estimate as u64 + 1;
+
+        usize::try_from(estimate.min(resolution.cell_count()))
+            .unwrap_or(usize::MAX)
+    }
+
+    /// This implementation returns the cells whose center is inside the circle,
+    /// like [`Polygon`](super::Polygon) does.
+    ///
+    /// The cells are found by flooding the grid from the center, through the
+    /// cells overlapping the circle.
+    fn to_cells(
+        &self,
+        resolution: Resolution,
+    ) -> Box<dyn Iterator<Item = CellIndex> + '_> {
+        let origin = self.center.to_cell(resolution);
+        let mut cells = Vec::new();
+        let mut visited = HashSet::default();
+        let mut queue = vec![origin];
+        visited.insert(origin);
+
+        while let Some(cell) = queue.pop() {
+            if self.contains(LatLng::from(cell)) {
+                cells.push(cell);
+            }
+            for neighbor in grid::neighbors(cell) {
+                if visited.insert(neighbor)
+                    && (self.contains(LatLng::from(neighbor))
+                        || grid::min_distance_m(neighbor, self.center)
+                            <= self.radius)
+                {
+                    queue.push(neighbor);
+                }
+            }
+        }
+
+        Box::new(cells.into_iter())
+    }
+}
+
+impl Coverable for Circle {}
+
+impl Regions for Circle {
+    fn regions(&self, resolution: Resolution) -> Vec<Region<'_>> {
+        vec![Region::Circle(*self, resolution)]
+    }
+}
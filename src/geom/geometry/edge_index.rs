@@ -0,0 +1,112 @@
+/// Minimum number of edges for a ring to be indexed.
+const MIN_EDGE_COUNT: usize = 32;
+
+/// Margin (in radians) added around the latitude range of every edge.
+///
+/// The containment test may nudge the latitude of the tested point by a few
+/// epsilons, this keeps the nearby edges in the candidates.
+const MARGIN: f64 = 1e-9;
+
+/// A spatial index of the edges of a ring, by latitude bands.
+///
+/// Every band lists, in order, the edges overlapping it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EdgeIndex {
+    /// Southernmost latitude covered by the bands.
+    south: f64,
+    /// Height of a band, in radians.
+    height: f64,
+    /// Offsets of every band into `edges` (the band `i` is
+    /// `edges[offsets[i]..offsets[i + 1]]`).
+    offsets: Vec<usize>,
+    /// Edges indexes, by band.
+    edges: Vec<u32>,
+}
+
+impl EdgeIndex {
+    /// Indexes the edges of the ring, if it's worth it.
+    pub fn new(ring: &geo::LineString<f64>) -> Option<Self> {
+        let count = ring.0.len().saturating_sub(1);
+        if count < MIN_EDGE_COUNT || u32::try_from(count).is_err() {
+            return None;
+        }
+        let ranges = ring
+            .lines()
+            .map(|line| {
+                let (min, max) = if line.start.y < line.end.y {
+                    (line.start.y, line.end.y)
+                } else {
+                    (line.end.y, line.start.y)
+                };
+                (min - MARGIN, max + MARGIN)
+            })
+            .collect::<Vec<_>>();
+        let south = ranges.iter().map(|range| range.0).fold(f64::MAX, f64::min);
+        let north = ranges.iter().map(|range| range.1).fold(f64::MIN, f64::max);
+
+        // Aim for a handful of edges per band, but use fewer bands when long
+        // edges would blow the memory usage up.
+        let mut band_count = (count / 8).clamp(1, 1 << 16);
+        loop {
+            let index = Self::build(&ranges, south, north, band_count);
+            if index.edges.len() <= 16 * count || band_count == 1 {
+                return Some(index);
+            }
+            band_count /= 2;
+        }
+    }
+
+    /// Returns the edges whose latitude range may contain the given latitude,
+    /// in order.
+    pub fn candidates(&self, lat: f64) -> impl Iterator<Item = usize> + '_ {
+        let band = band(self.south, self.height, self.offsets.len() - 1, lat);
+        self.edges[self.offsets[band]..self.offsets[band + 1]]
+            .iter()
+            .map(|&edge| edge as usize)
+    }
+
+    fn build(
+        ranges: &[(f64, f64)],
+        south: f64,
+        north: f64,
+        band_count: usize,
+    ) -> Self {
+        #[allow(clippy::cast_precision_loss)] // At most 2^16 bands.
+        let height = (north - south) / band_count as f64;
+        let mut bands = vec![Vec::new(); band_count];
+        for (edge, &(min, max)) in ranges.iter().enumerate() {
+            let edge = u32::try_from(edge).expect("checked edge count");
+            let first = band(south, height, band_count, min);
+            let last = band(south, height, band_count, max);
+            for band in &mut bands[first..=last] {
+                band.push(edge);
+            }
+        }
+
+        let mut offsets = Vec::with_capacity(band_count + 1);
+        let mut edges = Vec::new();
+        offsets.push(0);
+        for band in bands {
+            edges.extend(band);
+            offsets.push(edges.len());
+        }
+
+        Self {
+            south,
+            height,
+            offsets,
+            edges,
+        }
+    }
+}
+
+/// Returns the band containing the given latitude (clamped).
+fn band(south: f64, height: f64, band_count: usize, lat: f64) -> usize {
+    let band = ((lat - south) / height).floor();
+
+    // Truncate on purpose, clamped to the band range.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let band = band.max(0.) as usize;
+
+    band.min(band_count - 1)
+}
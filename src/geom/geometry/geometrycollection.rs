@@ -1,5 +1,13 @@
-use super::Geometry;
-use crate::{error::InvalidGeometry, geom::ToCells, CellIndex, Resolution};
+use super::{
+    polyfill::Region,
+    sorted::{self, SortedCells},
+    Geometry,
+};
+use crate::{
+    error::InvalidGeometry,
+    geom::{Coverable, Regions, ToCells},
+    CellIndex, Resolution,
+};
 use std::boxed::Box;
 
 /// A collection of [`geo::Geometry`].
@@ -66,6 +74,37 @@ impl<'a> GeometryCollection<'a> {
                 .collect::<Result<Vec<_>, _>>()?,
         ))
     }
+
+    /// Computes the coverage of the geometries, like [`ToCells::to_cells`],
+    /// but sorted and without duplicates.
+    ///
+    /// The cells of every geometry are sorted, then merged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::GeometryCollection, Resolution};
+    ///
+    /// let p1 = geo::Geometry::Point(geo::point!(x: 2.349014, y: 48.864716));
+    /// let p2 = geo::Geometry::Point(geo::point!(x: 2.294694, y: 48.858093));
+    /// let gc = geo::GeometryCollection::new_from(vec![p1, p2]);
+    /// let collection = GeometryCollection::from_degrees(gc)?;
+    /// let cells = collection
+    ///     .to_sorted_cells(Resolution::Seven)
+    ///     .collect::<Vec<_>>();
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn to_sorted_cells(
+        &self,
+        resolution: Resolution,
+    ) -> Box<dyn Iterator<Item = CellIndex> + '_> {
+        Box::new(sorted::merge(self.0.iter().map(
+            move |geometry| -> SortedCells<'_> {
+                geometry.to_sorted_cells(resolution)
+            },
+        )))
+    }
 }
 
 impl From<GeometryCollection<'_>> for geo::GeometryCollection<f64> {
@@ -93,3 +132,14 @@ impl ToCells for GeometryCollection<'_> {
         )
     }
 }
+
+impl Coverable for GeometryCollection<'_> {}
+
+impl Regions for GeometryCollection<'_> {
+    fn regions(&self, resolution: Resolution) -> Vec<Region<'_>> {
+        self.0
+            .iter()
+            .flat_map(|geometry| geometry.regions(resolution))
+            .collect()
+    }
+}
@@ -1,5 +1,8 @@
+use super::polyfill::{Classifier, Region};
 use crate::{
-    error::InvalidGeometry, geom::ToCells, CellIndex, LatLng, Resolution,
+    error::InvalidGeometry,
+    geom::{Coverable, Regions, ToCells},
+    grid, CellIndex, LatLng, Resolution,
 };
 use geo::Coord;
 use std::boxed::Box;
@@ -69,6 +72,37 @@ impl Line {
         Self::from_radians(line)
     }
 
+    /// Splits the line at the boundaries of the cells it goes through.
+    ///
+    /// Returns, in order, the cells along with the part of the line inside
+    /// the cell (coordinates in radians) and its great circle length, in
+    /// meters. Cells only touched at a single point are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::Line, Resolution};
+    ///
+    /// let line = geo::Line::new(
+    ///     geo::coord! { x: 3.13430935449378,  y: 45.866766242072146 },
+    ///     geo::coord! { x: 2.406730655500752, y: 47.08797812339847 },
+    /// );
+    /// let line = Line::from_degrees(line)?;
+    /// let length = line
+    ///     .split(Resolution::Seven)
+    ///     .iter()
+    ///     .map(|(_, _, length)| length)
+    ///     .sum::<f64>();
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn split(
+        &self,
+        resolution: Resolution,
+    ) -> Vec<(CellIndex, geo::LineString<f64>, f64)> {
+        split(std::iter::once(self.0), resolution)
+    }
+
     // Check that the line's coordinates are finite.
     fn check_coords(line: &geo::Line<f64>) -> Result<(), InvalidGeometry> {
         if !super::coord_is_valid(line.start)
@@ -99,6 +133,14 @@ impl ToCells for Line {
     }
 }
 
+impl Coverable for Line {}
+
+impl Regions for Line {
+    fn regions(&self, resolution: Resolution) -> Vec<Region<'_>> {
+        vec![Region::Edges(Classifier::linear([self.0], resolution))]
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 pub fn cells_count(line: geo::Line<f64>, resolution: Resolution) -> usize {
@@ -133,6 +175,70 @@ pub fn to_cells(
     cells.into_iter()
 }
 
+/// Splits a path, made of contiguous lines, at the boundaries of the cells it
+/// goes through.
+pub fn split(
+    lines: impl IntoIterator<Item = geo::Line<f64>>,
+    resolution: Resolution,
+) -> Vec<(CellIndex, geo::LineString<f64>, f64)> {
+    let mut pieces = Vec::new();
+    let mut current: Option<(CellIndex, Vec<LatLng>)> = None;
+
+    for line in lines {
+        let start = LatLng::new_unchecked(line.start.y, line.start.x);
+        let end = LatLng::new_unchecked(line.end.y, line.end.x);
+        let cell = start.to_cell(resolution);
+
+        // Continue the current piece, unless the path jumps elsewhere.
+        let mut piece = match current.take() {
+            Some((current_cell, points))
+                if current_cell == cell && points.last() == Some(&start) =>
+            {
+                points
+            }
+            Some((current_cell, points)) => {
+                pieces.extend(to_piece(current_cell, &points));
+                vec![start]
+            }
+            None => vec![start],
+        };
+        let mut cell = cell;
+        for crossing in grid::crossings(start, end, resolution) {
+            piece.push(crossing.point);
+            pieces.extend(to_piece(cell, &piece));
+            piece = vec![crossing.point];
+            cell = crossing.edge.destination();
+        }
+        piece.push(end);
+        current = Some((cell, piece));
+    }
+    if let Some((cell, points)) = current {
+        pieces.extend(to_piece(cell, &points));
+    }
+
+    pieces
+}
+
+/// Builds a piece of a split path, unless it has no length.
+fn to_piece(
+    cell: CellIndex,
+    points: &[LatLng],
+) -> Option<(CellIndex, geo::LineString<f64>, f64)> {
+    let length = points
+        .windows(2)
+        .map(|pair| pair[0].distance_m(pair[1]))
+        .sum::<f64>();
+    let coords = points
+        .iter()
+        .map(|point| Coord {
+            x: point.lng_radians(),
+            y: point.lat_radians(),
+        })
+        .collect();
+
+    (length > 0.).then(|| (cell, geo::LineString::new(coords), length))
+}
+
 // Returns the cell indexes at the start and end of the line for the given
 // resolution.
 fn start_end_cells(
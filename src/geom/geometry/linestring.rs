@@ -1,5 +1,12 @@
-use super::line;
-use crate::{error::InvalidGeometry, geom::ToCells, CellIndex, Resolution};
+use super::{
+    line,
+    polyfill::{Classifier, Region},
+};
+use crate::{
+    error::InvalidGeometry,
+    geom::{Coverable, Regions, ToCells},
+    CellIndex, Resolution,
+};
 use std::{borrow::Cow, boxed::Box};
 
 /// An ordered collection of two or more [`geo::Coord`]s, representing a
@@ -66,6 +73,36 @@ impl<'a> LineString<'a> {
         Self::check_coords(&line).map(|_| Self(Cow::Owned(line)))
     }
 
+    /// Splits the line at the boundaries of the cells it goes through.
+    ///
+    /// Returns, in order, the cells along with the part of the line inside
+    /// the cell (coordinates in radians) and its great circle length, in
+    /// meters. Cells only touched at a single point are skipped, and a cell
+    /// is listed again every time the line comes back into it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::LineString, Resolution};
+    ///
+    /// let line_string = geo::LineString::new(vec![
+    ///     geo::coord! { x: -0.5458558636632915, y: 47.47088771408784 },
+    ///     geo::coord! { x: 2.373611818843102,   y: 48.84548389122412 },
+    /// ]);
+    /// let line = LineString::from_degrees(line_string)?;
+    /// for (cell, _, length) in line.split(Resolution::Five) {
+    ///     println!("{cell}: {length:.0}m");
+    /// }
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn split(
+        &self,
+        resolution: Resolution,
+    ) -> Vec<(CellIndex, geo::LineString<f64>, f64)> {
+        line::split(self.0.lines(), resolution)
+    }
+
     // Check that the line's coordinates are finite.
     fn check_coords(
         line: &geo::LineString<f64>,
@@ -104,3 +141,14 @@ impl ToCells for LineString<'_> {
         )
     }
 }
+
+impl Coverable for LineString<'_> {}
+
+impl Regions for LineString<'_> {
+    fn regions(&self, resolution: Resolution) -> Vec<Region<'_>> {
+        vec![Region::Edges(Classifier::linear(
+            self.0.lines(),
+            resolution,
+        ))]
+    }
+}
@@ -1,23 +1,34 @@
 use crate::{
-    error::InvalidGeometry, geom::ToCells, CellIndex, Resolution, TWO_PI,
+    error::InvalidGeometry,
+    geom::{Coverable, Regions, ToCells},
+    CellIndex, Resolution, TWO_PI,
 };
 use std::{boxed::Box, f64::consts::PI};
 
 mod bbox;
+mod circle;
+mod edge_index;
 mod geometrycollection;
 mod line;
 mod linestring;
 mod multilinestring;
 mod multipoint;
 mod multipolygon;
+mod overlap;
 mod point;
+mod polyfill;
 mod polygon;
+mod polygon_index;
 mod rect;
 mod ring;
+mod sorted;
 mod triangle;
 
 use ring::Ring;
 
+pub(super) use polyfill::{Class, Region};
+
+pub use circle::Circle;
 pub use geometrycollection::GeometryCollection;
 pub use line::Line;
 pub use linestring::LineString;
@@ -26,6 +37,7 @@ pub use multipoint::MultiPoint;
 pub use multipolygon::MultiPolygon;
 pub use point::Point;
 pub use polygon::Polygon;
+pub use polygon_index::PolygonIndex;
 pub use rect::Rect;
 pub use triangle::Triangle;
 
@@ -164,6 +176,38 @@ impl<'a> Geometry<'a> {
             }
         })
     }
+
+    /// Computes the coverage of the geometry, like [`ToCells::to_cells`], but
+    /// sorted and without duplicates.
+    ///
+    /// The cells of every part of a collection are sorted, then merged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::Geometry, Resolution};
+    ///
+    /// let p = geo::point!(x: 2.349014, y: 48.864716);
+    /// let pe = geo::Geometry::Point(p);
+    /// let geom = Geometry::from_degrees(pe)?;
+    /// let cells = geom.to_sorted_cells(Resolution::Seven).collect::<Vec<_>>();
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn to_sorted_cells(
+        &self,
+        resolution: Resolution,
+    ) -> Box<dyn Iterator<Item = CellIndex> + '_> {
+        match *self {
+            Self::MultiPolygon(ref polygons) => {
+                polygons.to_sorted_cells(resolution)
+            }
+            Self::GeometryCollection(ref geometries) => {
+                geometries.to_sorted_cells(resolution)
+            }
+            _ => sorted::sort(self, resolution),
+        }
+    }
 }
 
 impl From<Geometry<'_>> for geo::Geometry<f64> {
@@ -244,6 +288,27 @@ impl ToCells for Geometry<'_> {
     }
 }
 
+impl Coverable for Geometry<'_> {}
+
+impl Regions for Geometry<'_> {
+    fn regions(&self, resolution: Resolution) -> Vec<Region<'_>> {
+        match *self {
+            Self::Point(ref point) => point.regions(resolution),
+            Self::Line(ref line) => line.regions(resolution),
+            Self::LineString(ref line) => line.regions(resolution),
+            Self::Polygon(ref polygon) => polygon.regions(resolution),
+            Self::MultiPoint(ref points) => points.regions(resolution),
+            Self::MultiLineString(ref lines) => lines.regions(resolution),
+            Self::MultiPolygon(ref polygons) => polygons.regions(resolution),
+            Self::GeometryCollection(ref geometries) => {
+                geometries.regions(resolution)
+            }
+            Self::Rect(ref rect) => rect.regions(resolution),
+            Self::Triangle(ref triangle) => triangle.regions(resolution),
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 // Check that the coordinate are finite and in a legit range.
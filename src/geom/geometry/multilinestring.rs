@@ -1,5 +1,9 @@
-use super::LineString;
-use crate::{error::InvalidGeometry, geom::ToCells, CellIndex, Resolution};
+use super::{polyfill::Region, LineString};
+use crate::{
+    error::InvalidGeometry,
+    geom::{Coverable, Regions, ToCells},
+    CellIndex, Resolution,
+};
 use std::boxed::Box;
 
 /// A collection of [`geo::LineString`].
@@ -78,6 +82,39 @@ impl<'a> MultiLineString<'a> {
                 .collect::<Result<Vec<_>, _>>()?,
         ))
     }
+
+    /// Splits the lines at the boundaries of the cells they go through.
+    ///
+    /// Returns, line after line, the cells along with the part of the line
+    /// inside the cell (coordinates in radians) and its great circle length,
+    /// in meters. See [`LineString::split`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::line_string;
+    /// use h3o::{geom::MultiLineString, Resolution};
+    ///
+    /// let line_string: geo::LineString<f64> = line_string![
+    ///     (x: 114.385771248293,   y: 30.612709316587612),
+    ///     (x: 126.53337527260373, y: 45.8089358995214),
+    ///     (x: 121.54475921995464, y: 31.22409481103989),
+    /// ];
+    /// let lines = geo::MultiLineString::new(vec![line_string]);
+    /// let lines = MultiLineString::from_degrees(lines)?;
+    /// let pieces = lines.split(Resolution::Three);
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn split(
+        &self,
+        resolution: Resolution,
+    ) -> Vec<(CellIndex, geo::LineString<f64>, f64)> {
+        self.0
+            .iter()
+            .flat_map(|line| line.split(resolution))
+            .collect()
+    }
 }
 
 impl From<MultiLineString<'_>> for geo::MultiLineString<f64> {
@@ -105,3 +142,14 @@ impl ToCells for MultiLineString<'_> {
         )
     }
 }
+
+impl Coverable for MultiLineString<'_> {}
+
+impl Regions for MultiLineString<'_> {
+    fn regions(&self, resolution: Resolution) -> Vec<Region<'_>> {
+        self.0
+            .iter()
+            .flat_map(|line| line.regions(resolution))
+            .collect()
+    }
+}
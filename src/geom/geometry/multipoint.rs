@@ -1,5 +1,9 @@
-use super::Point;
-use crate::{error::InvalidGeometry, geom::ToCells, CellIndex, Resolution};
+use super::{polyfill::Region, Point};
+use crate::{
+    error::InvalidGeometry,
+    geom::{Coverable, Regions, ToCells},
+    CellIndex, Resolution,
+};
 use std::boxed::Box;
 
 /// A collection of [`geo::Point`]s.
@@ -94,3 +98,14 @@ impl ToCells for MultiPoint {
         )
     }
 }
+
+impl Coverable for MultiPoint {}
+
+impl Regions for MultiPoint {
+    fn regions(&self, resolution: Resolution) -> Vec<Region<'_>> {
+        self.0
+            .iter()
+            .flat_map(|point| point.regions(resolution))
+            .collect()
+    }
+}
@@ -1,6 +1,14 @@
-use super::Polygon;
-use crate::{error::InvalidGeometry, geom::ToCells, CellIndex, Resolution};
-use std::boxed::Box;
+use super::{overlap::area_rads2, polyfill::Region, sorted, Polygon};
+use crate::{
+    error::InvalidGeometry,
+    geom::{Coverable, Regions, ToCells},
+    CellIndex, Resolution,
+};
+use geo::BooleanOps;
+use std::{
+    boxed::Box,
+    collections::{btree_map::Entry, BTreeMap},
+};
 
 /// A collection of [`geo::Polygon`].
 #[derive(Clone, Debug, PartialEq)]
@@ -78,6 +86,213 @@ impl<'a> MultiPolygon<'a> {
                 .collect::<Result<Vec<_>, _>>()?,
         ))
     }
+
+    /// Computes, for every cell touching the polygons, the fraction of the
+    /// cell area covered by the polygons.
+    ///
+    /// Cells are sorted, fractions are in `]0; 1]`. Overlapping polygons are
+    /// merged: an area covered twice is only counted once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::MultiPolygon, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    /// ];
+    /// let mp = geo::MultiPolygon::new(vec![p]);
+    /// let multipolygon = MultiPolygon::from_radians(&mp)?;
+    /// let coverage = multipolygon.coverage(Resolution::Seven);
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn coverage(&self, resolution: Resolution) -> Vec<(CellIndex, f64)> {
+        self.clip(resolution)
+            .into_iter()
+            .map(|(cell, fraction, _)| (cell, fraction))
+            .collect()
+    }
+
+    /// Clips the polygons by every cell they touch.
+    ///
+    /// Returns, for every cell, the fraction of the cell area covered by the
+    /// polygons and the covered part of the cell (coordinates in radians).
+    ///
+    /// Cells are sorted, fractions are in `]0; 1]`. Overlapping polygons are
+    /// merged: an area covered twice is only counted once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::MultiPolygon, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    /// ];
+    /// let mp = geo::MultiPolygon::new(vec![p]);
+    /// let multipolygon = MultiPolygon::from_radians(&mp)?;
+    /// for (cell, fraction, geometry) in multipolygon.clip(Resolution::Seven) {
+    ///     println!("{cell}: {:.2}%", fraction * 100.);
+    /// }
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn clip(
+        &self,
+        resolution: Resolution,
+    ) -> Vec<(CellIndex, f64, geo::MultiPolygon<f64>)> {
+        let mut clipped = BTreeMap::<_, (f64, geo::MultiPolygon<f64>)>::new();
+        for (cell, fraction, geometry) in
+            self.0.iter().flat_map(|polygon| polygon.clip(resolution))
+        {
+            match clipped.entry(cell) {
+                Entry::Vacant(entry) => {
+                    entry.insert((fraction, geometry));
+                }
+                Entry::Occupied(mut entry) => {
+                    let &mut (ref mut covered, ref mut merged) =
+                        entry.get_mut();
+                    if fraction >= 1. {
+                        *covered = fraction;
+                        *merged = geometry;
+                    } else if *covered < 1. {
+                        // The polygons may overlap: merge the covered parts
+                        // rather than summing the fractions.
+                        *merged = merged.union(&geometry);
+                        *covered = (area_rads2(merged) / cell.area_rads2())
+                            .clamp(0., 1.);
+                    }
+                }
+            }
+        }
+
+        clipped
+            .into_iter()
+            .map(|(cell, (fraction, geometry))| (cell, fraction, geometry))
+            .collect()
+    }
+
+    /// Computes the coverage of the polygons, like [`ToCells::to_cells`], but
+    /// sorted and without duplicates.
+    ///
+    /// The cells of every polygon are sorted, then merged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::MultiPolygon, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    /// ];
+    /// let mp = geo::MultiPolygon::new(vec![p]);
+    /// let multipolygon = MultiPolygon::from_radians(&mp)?;
+    /// let cells = multipolygon
+    ///     .to_sorted_cells(Resolution::Seven)
+    ///     .collect::<Vec<_>>();
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn to_sorted_cells(
+        &self,
+        resolution: Resolution,
+    ) -> Box<dyn Iterator<Item = CellIndex> + '_> {
+        Box::new(sorted::merge(
+            self.0
+                .iter()
+                .map(move |polygon| sorted::sort(polygon, resolution)),
+        ))
+    }
+
+    /// Returns an estimation of the number of cells returned by
+    /// [`ToCells::to_cells`], based on the area of the polygons.
+    ///
+    /// See [`Polygon::estimate_cells_count`] for the error bound.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::MultiPolygon, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    /// ];
+    /// let mp = geo::MultiPolygon::new(vec![p]);
+    /// let multipolygon = MultiPolygon::from_radians(&mp)?;
+    /// let count = multipolygon.estimate_cells_count(Resolution::Nine);
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn estimate_cells_count(&self, resolution: Resolution) -> usize {
+        self.0
+            .iter()
+            .map(|polygon| polygon.estimate_cells_count(resolution))
+            .fold(0, usize::saturating_add)
+    }
+
+    /// Returns an upper bound to the number of cells returned by
+    /// [`ToCells::to_cells`], based on the area and the perimeter of the
+    /// polygons.
+    ///
+    /// See [`Polygon::cells_count_upper_bound`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::MultiPolygon, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    /// ];
+    /// let mp = geo::MultiPolygon::new(vec![p]);
+    /// let multipolygon = MultiPolygon::from_radians(&mp)?;
+    /// let bound = multipolygon.cells_count_upper_bound(Resolution::Nine);
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn cells_count_upper_bound(&self, resolution: Resolution) -> usize {
+        self.0
+            .iter()
+            .map(|polygon| polygon.cells_count_upper_bound(resolution))
+            .fold(0, usize::saturating_add)
+    }
+
+    /// Initialize a collection from already converted polygons.
+    pub(super) const fn from_polygons(polygons: Vec<Polygon<'a>>) -> Self {
+        Self(polygons)
+    }
+}
+
+impl<'a> From<Polygon<'a>> for MultiPolygon<'a> {
+    fn from(value: Polygon<'a>) -> Self {
+        Self(vec![value])
+    }
 }
 
 impl From<MultiPolygon<'_>> for geo::MultiPolygon<f64> {
@@ -105,3 +320,14 @@ impl ToCells for MultiPolygon<'_> {
         )
     }
 }
+
+impl Coverable for MultiPolygon<'_> {}
+
+impl Regions for MultiPolygon<'_> {
+    fn regions(&self, resolution: Resolution) -> Vec<Region<'_>> {
+        self.0
+            .iter()
+            .flat_map(|polygon| polygon.regions(resolution))
+            .collect()
+    }
+}
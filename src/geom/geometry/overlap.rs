@@ -0,0 +1,260 @@
+//! Exact overlap between polygons and cells.
+
+use crate::{index::Triangle, CellIndex, LatLng, TWO_PI};
+use geo::{BooleanOps, Coord, LineString, MapCoords};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// A polygon (in radians), ready to be clipped by cells.
+pub struct Clipper {
+    /// Polygon, with longitudes unwrapped if it crosses the antimeridian.
+    polygon: geo::Polygon<f64>,
+    /// Longitude of the center of the polygon bounding box.
+    center: f64,
+}
+
+impl Clipper {
+    /// Prepares the polygon for clipping.
+    ///
+    /// `is_transmeridian` tells whether the polygon crosses the antimeridian.
+    pub fn new(polygon: geo::Polygon<f64>, is_transmeridian: bool) -> Self {
+        let mut polygon = polygon;
+        if is_transmeridian {
+            polygon.exterior_mut(unwrap);
+            polygon.interiors_mut(|rings| {
+                for ring in rings {
+                    unwrap(ring);
+                }
+            });
+        }
+        let (min, max) = lng_bounds(polygon.exterior().coords());
+
+        Self {
+            polygon,
+            center: f64::midpoint(min, max),
+        }
+    }
+
+    /// Clips the polygon by the cell.
+    ///
+    /// Returns the fraction of the cell area covered by the polygon, and the
+    /// covered part of the cell (if any).
+    pub fn clip(
+        &self,
+        cell: CellIndex,
+    ) -> Option<(f64, geo::MultiPolygon<f64>)> {
+        // Use the copy of the cell the closest to the polygon, longitude-wise.
+        let boundary = cell_ring(cell, self.center);
+        let cell_polygon = geo::Polygon::new(boundary, Vec::new());
+        let clipped = self.polygon.intersection(&cell_polygon);
+        let area = area_rads2(&clipped);
+        (area > 0.).then(|| {
+            let fraction = (area / cell.area_rads2()).clamp(0., 1.);
+            (fraction, normalize(clipped))
+        })
+    }
+}
+
+/// Returns the boundary of the cell, as a closed ring in radians.
+///
+/// Longitudes are shifted by a multiple of a full turn to move the cell as
+/// close as possible to `center` (and unwrapped if the cell crosses the
+/// antimeridian).
+///
+/// A cell containing a pole is turned into the area between its boundary and
+/// the pole, over the full turn centered on `center`.
+pub fn cell_ring(cell: CellIndex, center: f64) -> LineString<f64> {
+    let vertices = cell
+        .boundary()
+        .iter()
+        .map(|vertex| Coord {
+            x: vertex.lng_radians(),
+            y: vertex.lat_radians(),
+        })
+        .collect::<Vec<_>>();
+
+    // The boundary winds around the cell center, and thus around the pole if
+    // the cell contains it.
+    let winding = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(from, to)| {
+            let delta = to.x - from.x;
+            TWO_PI.mul_add(-(delta / TWO_PI).round(), delta)
+        })
+        .sum::<f64>();
+    if winding.abs() > PI {
+        return polar_ring(vertices, center);
+    }
+
+    let mut ring = LineString::from(vertices);
+    ring.close();
+    let (min, max) = lng_bounds(ring.coords());
+    if max - min > PI {
+        unwrap(&mut ring);
+    }
+
+    let (min, max) = lng_bounds(ring.coords());
+    let offset = ((center - f64::midpoint(min, max)) / TWO_PI).round() * TWO_PI;
+    if offset != 0. {
+        for coord in ring.coords_mut() {
+            coord.x += offset;
+        }
+    }
+
+    ring
+}
+
+/// Moves every part of the geometry back into `[-π, π]`, splitting it at the
+/// antimeridian if needed.
+pub fn normalize(geometry: geo::MultiPolygon<f64>) -> geo::MultiPolygon<f64> {
+    let (min, max) = lng_bounds(
+        geometry
+            .iter()
+            .flat_map(|polygon| polygon.exterior().coords()),
+    );
+    if min >= -PI && max <= PI {
+        return geometry;
+    }
+
+    // Truncate on purpose, the geometry spans a handful of turns at most.
+    #[allow(clippy::cast_possible_truncation)]
+    let (first, last) = (
+        ((min + PI) / TWO_PI).floor() as i32,
+        ((max - PI) / TWO_PI).ceil() as i32,
+    );
+    let parts = (first..=last)
+        .flat_map(|turn| {
+            let offset = f64::from(turn) * TWO_PI;
+            let window = geo::Rect::new(
+                Coord {
+                    x: offset - PI,
+                    y: -FRAC_PI_2,
+                },
+                Coord {
+                    x: offset + PI,
+                    y: FRAC_PI_2,
+                },
+            )
+            .to_polygon();
+            geometry
+                .intersection(&geo::MultiPolygon::new(vec![window]))
+                .into_iter()
+                .map(move |part| {
+                    part.map_coords(|coord| Coord {
+                        x: coord.x - offset,
+                        y: coord.y,
+                    })
+                })
+        })
+        .collect();
+
+    geo::MultiPolygon::new(parts)
+}
+
+/// Computes the spherical area, in radians², of a polygon whose coordinates
+/// are in radians.
+///
+/// Uses the same spherical triangles as [`CellIndex::area_rads2`], to get
+/// consistent results.
+pub fn area_rads2(polygons: &geo::MultiPolygon<f64>) -> f64 {
+    polygons
+        .iter()
+        .map(|polygon| {
+            polygon
+                .interiors()
+                .iter()
+                .fold(ring_area(polygon.exterior()), |area, ring| {
+                    area - ring_area(ring)
+                })
+        })
+        .sum::<f64>()
+        .max(0.)
+}
+
+// -----------------------------------------------------------------------------
+
+/// Computes the spherical area of a ring, using a triangle fan.
+fn ring_area(ring: &LineString<f64>) -> f64 {
+    let coords = &ring.0;
+    if coords.len() < 4 {
+        return 0.;
+    }
+    let apex = coords[0];
+    let to_latlng = |coord: Coord<f64>| LatLng::new_unchecked(coord.y, coord.x);
+
+    // Triangles are signed according to their orientation, to support
+    // non-convex rings.
+    coords[1..coords.len() - 1]
+        .windows(2)
+        .map(|pair| {
+            let (b, c) = (pair[0], pair[1]);
+            let cross = (b.x - apex.x)
+                .mul_add(c.y - apex.y, -(b.y - apex.y) * (c.x - apex.x));
+            let area =
+                Triangle::new(to_latlng(apex), to_latlng(b), to_latlng(c))
+                    .area();
+            area.copysign(cross)
+        })
+        .sum::<f64>()
+        .abs()
+}
+
+/// Returns the range of longitudes of the coordinates.
+fn lng_bounds<'a>(coords: impl Iterator<Item = &'a Coord<f64>>) -> (f64, f64) {
+    coords.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), coord| {
+        (min.min(coord.x), max.max(coord.x))
+    })
+}
+
+/// Turns the boundary of a cell containing a pole into the area between the
+/// boundary and the pole, over the full turn centered on `center`.
+fn polar_ring(vertices: Vec<Coord<f64>>, center: f64) -> LineString<f64> {
+    let (west, east) = (center - PI, center + PI);
+    let mut coords = vertices
+        .into_iter()
+        .map(|coord| Coord {
+            x: TWO_PI.mul_add(-((coord.x - west) / TWO_PI).floor(), coord.x),
+            y: coord.y,
+        })
+        .collect::<Vec<_>>();
+    coords.sort_unstable_by(|a, b| a.x.total_cmp(&b.x));
+
+    // Latitude where the boundary crosses the edges of the turn, between the
+    // last vertex and the first one.
+    let (first, last) = (coords[0], coords[coords.len() - 1]);
+    let gap = first.x + TWO_PI - last.x;
+    let crossing = if gap > 0. {
+        (first.y - last.y).mul_add((east - last.x) / gap, last.y)
+    } else {
+        first.y
+    };
+    let pole = FRAC_PI_2.copysign(first.y);
+
+    let mut ring = std::iter::once(Coord {
+        x: west,
+        y: crossing,
+    })
+    .chain(coords)
+    .chain([
+        Coord {
+            x: east,
+            y: crossing,
+        },
+        Coord { x: east, y: pole },
+        Coord { x: west, y: pole },
+    ])
+    .collect::<LineString<f64>>();
+    ring.close();
+
+    ring
+}
+
+/// Unwraps the longitudes of a ring crossing the antimeridian, so that the
+/// coordinates are contiguous.
+fn unwrap(ring: &mut LineString<f64>) {
+    for coord in ring.coords_mut() {
+        if coord.x < 0. {
+            coord.x += TWO_PI;
+        }
+    }
+}
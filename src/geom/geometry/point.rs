@@ -1,6 +1,7 @@
+use super::polyfill::{Classifier, Region};
 use crate::{
     error::{InvalidGeometry, InvalidLatLng},
-    geom::ToCells,
+    geom::{Coverable, Regions, ToCells},
     CellIndex, LatLng, Resolution,
 };
 use std::boxed::Box;
@@ -92,3 +93,13 @@ impl ToCells for Point {
         Box::new(std::iter::once(ll.to_cell(resolution)))
     }
 }
+
+impl Coverable for Point {}
+
+impl Regions for Point {
+    fn regions(&self, resolution: Resolution) -> Vec<Region<'_>> {
+        let coord = geo::Coord::from(self.0);
+        let line = geo::Line::new(coord, coord);
+        vec![Region::Edges(Classifier::linear([line], resolution))]
+    }
+}
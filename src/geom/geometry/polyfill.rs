@@ -0,0 +1,279 @@
+//! Hierarchical polygon filling, from the base cells down to the target
+//! resolution.
+//!
+//! The cells of a polygon are those whose center is inside the polygon. Coarse
+//! cells are classified against the polygon as a whole: when no edge gets
+//! close enough to the descendants of a cell, they are all on the same side
+//! of the outline as the cell center and the cell is either entirely in or
+//! out. Only the cells along the outline need to be refined.
+//!
+//! The same classification, extended to lines and circles, drives the
+//! [`Coverer`](crate::geom::Coverer).
+
+use super::{Circle, Polygon};
+use crate::{CellIndex, LatLng, Resolution, TWO_PI};
+use geo::{coord, Coord, Intersects};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// Position of the descendants of a cell relative to a polygon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Class {
+    /// Every descendant is in the polygon.
+    Inside,
+    /// No descendant is in the polygon.
+    Outside,
+    /// The descendants may be on both sides, holds the edges that are close
+    /// to the cell.
+    Boundary(Vec<usize>),
+}
+
+/// A part of a geometry, against which cells are classified.
+#[derive(Debug, Clone)]
+pub enum Region<'g> {
+    /// A polygon, or lines.
+    Edges(Classifier<'g, 'g>),
+    /// A circle, classified by the center of the cells at the given
+    /// resolution.
+    Circle(Circle, Resolution),
+}
+
+impl Region<'_> {
+    /// Returns the index of every edge of the region.
+    pub fn edges(&self) -> Vec<usize> {
+        match *self {
+            Self::Edges(ref classifier) => classifier.edges(),
+            Self::Circle(..) => Vec::new(),
+        }
+    }
+
+    /// Classifies the cell, considering only the given edges.
+    pub fn classify(&self, cell: CellIndex, edges: &[usize]) -> Class {
+        match *self {
+            Self::Edges(ref classifier) => classifier.classify(cell, edges),
+            Self::Circle(ref circle, resolution) => {
+                circle.classify(cell, resolution)
+            }
+        }
+    }
+}
+
+/// A polygon edge.
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    /// Edge coordinates, in the same space than the ring containment test.
+    line: geo::Line<f64>,
+    /// Whether the ring crosses the antimeridian.
+    is_transmeridian: bool,
+}
+
+/// Classifies cells against a polygon, for a given target resolution.
+#[derive(Debug, Clone)]
+pub struct Classifier<'p, 'a> {
+    /// The polygon to fill, if any (lines have no interior).
+    polygon: Option<&'p Polygon<'a>>,
+    /// Edges of every ring of the polygon.
+    edges: Vec<Edge>,
+    /// Resolution of the filling.
+    resolution: Resolution,
+    /// Whether whole cells are classified, rather than only their center.
+    is_areal: bool,
+}
+
+impl<'p, 'a> Classifier<'p, 'a> {
+    /// Initializes a classifier of the cell centers.
+    pub fn new(polygon: &'p Polygon<'a>, resolution: Resolution) -> Self {
+        Self::with_mode(polygon, resolution, false)
+    }
+
+    /// Initializes a classifier of the whole cells: a cell is inside (resp.
+    /// outside) only if every point of its descendants, at the target
+    /// resolution, is inside (resp. outside) the polygon.
+    ///
+    /// Cells at the target resolution can be on the boundary.
+    pub fn areal(polygon: &'p Polygon<'a>, resolution: Resolution) -> Self {
+        Self::with_mode(polygon, resolution, true)
+    }
+
+    /// Initializes a classifier of the whole cells against lines: a cell is
+    /// outside only if no line gets close to its descendants, and is never
+    /// inside.
+    pub fn linear(
+        lines: impl IntoIterator<Item = geo::Line<f64>>,
+        resolution: Resolution,
+    ) -> Self {
+        let edges = lines
+            .into_iter()
+            .map(|line| {
+                let is_transmeridian = (line.start.x - line.end.x).abs() > PI;
+                Edge {
+                    line: if is_transmeridian {
+                        geo::Line::new(unwrap(line.start), unwrap(line.end))
+                    } else {
+                        line
+                    },
+                    is_transmeridian,
+                }
+            })
+            .collect();
+
+        Self {
+            polygon: None,
+            edges,
+            resolution,
+            is_areal: true,
+        }
+    }
+
+    fn with_mode(
+        polygon: &'p Polygon<'a>,
+        resolution: Resolution,
+        is_areal: bool,
+    ) -> Self {
+        let edges = polygon
+            .rings()
+            .flat_map(|ring| {
+                let is_transmeridian = ring.is_transmeridian();
+                ring.geom().lines().map(move |line| Edge {
+                    line: if is_transmeridian {
+                        geo::Line::new(unwrap(line.start), unwrap(line.end))
+                    } else {
+                        line
+                    },
+                    is_transmeridian,
+                })
+            })
+            .collect();
+
+        Self {
+            polygon: Some(polygon),
+            edges,
+            resolution,
+            is_areal,
+        }
+    }
+
+    /// Returns the index of every edge of the polygon.
+    pub fn edges(&self) -> Vec<usize> {
+        (0..self.edges.len()).collect()
+    }
+
+    /// Classifies the cell, considering only the given edges.
+    ///
+    /// The edges must include every edge close to the cell (e.g. those
+    /// returned for its parent).
+    pub fn classify(&self, cell: CellIndex, edges: &[usize]) -> Class {
+        let center = LatLng::from(cell);
+        let side = || {
+            let coord = coord! {
+                x: center.lng_radians(),
+                y: center.lat_radians(),
+            };
+            if self.polygon.is_some_and(|polygon| polygon.contains(coord)) {
+                Class::Inside
+            } else {
+                Class::Outside
+            }
+        };
+
+        let resolution = cell.resolution();
+        if resolution == self.resolution && !self.is_areal {
+            return side();
+        }
+
+        let radius = radius(resolution, self.resolution, self.is_areal);
+        let (lng, lat) = bounds(center, radius);
+
+        let mut close = Vec::new();
+        for &index in edges {
+            let edge = &self.edges[index];
+            let is_close = unwrap_range(lng, edge.is_transmeridian)
+                .into_iter()
+                .flatten()
+                .any(|lng| {
+                    let rect = geo::Rect::new(
+                        coord! { x: lng.0, y: lat.0 },
+                        coord! { x: lng.1, y: lat.1 },
+                    );
+                    // Points are stored as degenerate lines.
+                    edge.line.intersects(&rect)
+                        || rect.intersects(&edge.line.start)
+                });
+            if is_close {
+                close.push(index);
+            }
+        }
+
+        if close.is_empty() {
+            side()
+        } else {
+            Class::Boundary(close)
+        }
+    }
+}
+
+/// Returns a bound of the distance (in radians) from the center of a cell at
+/// the given resolution to the centers of its descendants at the target
+/// resolution (or to every point of them, for whole cells).
+pub fn radius(
+    resolution: Resolution,
+    target: Resolution,
+    is_areal: bool,
+) -> f64 {
+    // The center of a child is less than an edge length away from the center
+    // of its parent (and, at the target resolution, from the points of the
+    // cell).
+    Resolution::range(resolution, target)
+        .take_while(|&resolution| is_areal || resolution != target)
+        .map(|resolution| 2. * resolution.edge_length_rads())
+        .sum()
+}
+
+/// Returns the longitude and latitude ranges of the spherical cap of the given
+/// radius (in radians).
+///
+/// A cap containing a pole spans every longitude.
+fn bounds(center: LatLng, radius: f64) -> ((f64, f64), (f64, f64)) {
+    let (lat, lng) = (center.lat_radians(), center.lng_radians());
+    let (south, north) = (lat - radius, lat + radius);
+    if south <= -FRAC_PI_2 || north >= FRAC_PI_2 {
+        return ((-PI, PI), (south.max(-FRAC_PI_2), north.min(FRAC_PI_2)));
+    }
+    let half_width = (radius.sin() / lat.cos()).asin();
+
+    ((lng - half_width, lng + half_width), (south, north))
+}
+
+/// Maps a longitude range into the coordinate space of a ring, split in two
+/// when it spans the seam of that space.
+fn unwrap_range(
+    range: (f64, f64),
+    is_transmeridian: bool,
+) -> [Option<(f64, f64)>; 2] {
+    // Rings crossing the antimeridian have their negative longitudes shifted
+    // by a full turn.
+    let (west, east) = if is_transmeridian {
+        (0., TWO_PI)
+    } else {
+        (-PI, PI)
+    };
+    let shift = if range.0 < west {
+        TWO_PI
+    } else if range.0 >= east {
+        -TWO_PI
+    } else {
+        0.
+    };
+    let (start, end) = (range.0 + shift, range.1 + shift);
+
+    if end <= east {
+        [Some((start, end)), None]
+    } else {
+        [Some((start, east)), Some((west, end - TWO_PI))]
+    }
+}
+
+/// Maps a coordinate into the space of a ring crossing the antimeridian.
+fn unwrap(mut coord: Coord<f64>) -> Coord<f64> {
+    coord.x += f64::from(u8::from(coord.x < 0.)) * TWO_PI;
+    coord
+}
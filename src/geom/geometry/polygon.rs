@@ -1,10 +1,24 @@
-use super::{bbox, Geometry, Ring};
+use super::{
+    bbox,
+    overlap::{cell_ring, normalize, Clipper},
+    polyfill::{Class, Classifier, Region},
+    Geometry, Ring,
+};
 use crate::{
-    error::InvalidGeometry, geom::ToCells, CellIndex, LatLng, Resolution,
+    error::InvalidGeometry,
+    geom::{Coverable, Regions, ToCells},
+    CellIndex, LatLng, Resolution,
 };
 use ahash::{HashSet, HashSetExt};
 use geo::{coord, Coord, CoordsIter};
-use std::{borrow::Cow, boxed::Box, cmp, collections::VecDeque};
+use std::{
+    borrow::Cow,
+    boxed::Box,
+    cmp,
+    collections::VecDeque,
+    f64::consts::{FRAC_PI_2, PI},
+    rc::Rc,
+};
 
 /// A bounded two-dimensional area.
 #[derive(Clone, Debug, PartialEq)]
@@ -89,8 +103,319 @@ impl<'a> Polygon<'a> {
         })
     }
 
+    /// Computes, for every cell touching the polygon, the fraction of the cell
+    /// area covered by the polygon.
+    ///
+    /// Cells are returned in no particular order, fractions are in `]0; 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::Polygon, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    /// ];
+    /// let polygon = Polygon::from_radians(&p)?;
+    /// let coverage = polygon.coverage(Resolution::Seven).collect::<Vec<_>>();
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    pub fn coverage(
+        &self,
+        resolution: Resolution,
+    ) -> impl Iterator<Item = (CellIndex, f64)> + '_ {
+        self.clip(resolution)
+            .map(|(cell, fraction, _)| (cell, fraction))
+    }
+
+    /// Clips the polygon by every cell it touches.
+    ///
+    /// Returns, for every cell, the fraction of the cell area covered by the
+    /// polygon and the covered part of the cell (coordinates in radians).
+    ///
+    /// Cells are returned in no particular order, fractions are in `]0; 1]`.
+    /// The covered parts are split at the antimeridian, so that longitudes are
+    /// in `[-π, π]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::Polygon, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    /// ];
+    /// let polygon = Polygon::from_radians(&p)?;
+    /// for (cell, fraction, geometry) in polygon.clip(Resolution::Seven) {
+    ///     println!("{cell}: {:.2}%", fraction * 100.);
+    /// }
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    pub fn clip(
+        &self,
+        resolution: Resolution,
+    ) -> impl Iterator<Item = (CellIndex, f64, geo::MultiPolygon<f64>)> + '_
+    {
+        // Only the cells close to the polygon outline can be partially
+        // covered, the others are either fully covered or not at all.
+        let mut seen = HashSet::new();
+        let edge_cells = self
+            .interiors()
+            .chain(std::iter::once(self.exterior()))
+            .flat_map(|ring| get_edge_cells(ring, resolution))
+            .filter(|cell| seen.insert(*cell))
+            .collect::<Vec<_>>();
+        let band = edge_cells
+            .into_iter()
+            .flat_map(|cell| cell.grid_disk_safe(2))
+            .collect::<HashSet<_>>();
+
+        let clipper =
+            Clipper::new(self.clone().into(), self.bbox().max().x > PI);
+        let mut partial = band
+            .iter()
+            .filter_map(|&cell| {
+                clipper
+                    .clip(cell)
+                    .map(|(fraction, geometry)| (cell, fraction, geometry))
+            })
+            .collect::<Vec<_>>();
+        partial.sort_unstable_by_key(|&(cell, _, _)| cell);
+
+        let full =
+            self.to_cells(resolution)
+                .filter(move |cell| !band.contains(cell))
+                .map(|cell| {
+                    let geometry = normalize(geo::MultiPolygon::new(vec![
+                        geo::Polygon::new(cell_ring(cell, 0.), Vec::new()),
+                    ]));
+                    (cell, 1., geometry)
+                });
+
+        partial.into_iter().chain(full)
+    }
+
+    /// Computes the compacted coverage of the polygon at the specified
+    /// resolution.
+    ///
+    /// The result is the same as compacting the output of
+    /// [`ToCells::to_cells`], but it's computed top-down from the base cells:
+    /// the cells of the target resolution are only materialized along the
+    /// outline of the polygon, making it suitable for large polygons at fine
+    /// resolutions.
+    ///
+    /// Cells are returned in no particular order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::Polygon, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    /// ];
+    /// let polygon = Polygon::from_radians(&p)?;
+    /// let cells = polygon.to_compacted_cells(Resolution::Ten);
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn to_compacted_cells(&self, resolution: Resolution) -> Vec<CellIndex> {
+        let classifier = Classifier::new(self, resolution);
+        let edges = classifier.edges();
+        let mut cells = Vec::new();
+        for cell in CellIndex::base_cells() {
+            fill_compacted(&classifier, cell, &edges, &mut cells);
+        }
+        cells
+    }
+
+    /// Computes the coverage of the polygon at the specified resolution, using
+    /// a bounded amount of memory.
+    ///
+    /// The output is the same set of cells as [`ToCells::to_cells`], but the
+    /// cells are generated by a depth-first traversal of the hierarchy, from
+    /// the base cells: the memory usage depends on the outline of the polygon,
+    /// not on its area (i.e. the number of cells returned).
+    ///
+    /// Cells are returned in no particular order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::Polygon, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    /// ];
+    /// let polygon = Polygon::from_radians(&p)?;
+    /// for cell in polygon.to_cells_streaming(Resolution::Nine) {
+    ///     println!("{cell}");
+    /// }
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    pub fn to_cells_streaming(
+        &self,
+        resolution: Resolution,
+    ) -> impl Iterator<Item = CellIndex> + '_ {
+        let classifier = Classifier::new(self, resolution);
+        let edges = Rc::<[usize]>::from(classifier.edges());
+        // Cells yet to be classified, with the edges close to them.
+        let mut stack = CellIndex::base_cells()
+            .map(|cell| (cell, Rc::clone(&edges)))
+            .collect::<Vec<_>>();
+
+        std::iter::from_fn(move || {
+            while let Some((cell, edges)) = stack.pop() {
+                match classifier.classify(cell, &edges) {
+                    Class::Inside => return Some(cell),
+                    Class::Outside => (),
+                    Class::Boundary(edges) => {
+                        let edges = Rc::<[usize]>::from(edges);
+                        // Boundary cells are always coarser than the
+                        // target resolution.
+                        stack.extend(
+                            cell.resolution()
+                                .succ()
+                                .into_iter()
+                                .flat_map(|resolution| {
+                                    cell.children(resolution)
+                                })
+                                .map(|child| (child, Rc::clone(&edges))),
+                        );
+                    }
+                }
+            }
+            None
+        })
+        .flat_map(move |cell| cell.children(resolution))
+    }
+
+    /// Returns an estimation of the number of cells returned by
+    /// [`ToCells::to_cells`], based on the area of the polygon.
+    ///
+    /// Unlike [`ToCells::max_cells_count`], which derives from the bounding
+    /// box, the estimate doesn't depend on the shape (e.g. thin diagonal or
+    /// L-shaped polygons) but it's not an upper bound (see
+    /// [`Self::cells_count_upper_bound`] for that).
+    ///
+    /// # Accuracy
+    ///
+    /// The estimate is the spherical area of the polygon divided by the
+    /// average cell area of the resolution ([`Resolution::area_rads2`]).
+    ///
+    /// In practice, the outline is negligible for polygons spanning many cells
+    /// and the error comes from the local cell area, which varies slowly but
+    /// ranges from half (around the pentagons) to 1.21 times the average area
+    /// of the resolution. For a guaranteed bound, see
+    /// [`Self::cells_count_upper_bound`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::Polygon, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    /// ];
+    /// let polygon = Polygon::from_radians(&p)?;
+    /// let count = polygon.estimate_cells_count(Resolution::Nine);
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn estimate_cells_count(&self, resolution: Resolution) -> usize {
+        let estimate = (self.area_rads2() / resolution.area_rads2()).round();
+
+        // Truncate on purpose.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let estimate = estimate as usize;
+
+        estimate
+    }
+
+    /// Returns an upper bound to the number of cells returned by
+    /// [`ToCells::to_cells`], based on the area and the perimeter of the
+    /// polygon.
+    ///
+    /// Every cell whose center is inside the polygon lies within the polygon
+    /// extended by the largest cell radius. The area of that region, divided
+    /// by the smallest cell area, bounds the number of cells.
+    ///
+    /// This is usually a lot tighter than [`ToCells::max_cells_count`] for
+    /// polygons that poorly fill their bounding box, but may be larger for
+    /// tiny polygons (a few cells).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::{Polygon, ToCells}, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9726707149994819),
+    ///     (x: 0.6573835290630796, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9735034901250053),
+    ///     (x: 0.6559997912129759, y: 0.9726707149994819),
+    /// ];
+    /// let polygon = Polygon::from_radians(&p)?;
+    /// let bound = polygon.cells_count_upper_bound(Resolution::Nine);
+    /// assert!(polygon.to_cells(Resolution::Nine).count() <= bound);
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn cells_count_upper_bound(&self, resolution: Resolution) -> usize {
+        // Distance from a cell center to its farthest vertex, and smallest
+        // cell area, relative to the averages of the resolution (the extremes
+        // are 1.2684 and 0.5045, reached at the finest resolutions).
+        const RADIUS_RATIO: f64 = 1.3;
+        const AREA_RATIO: f64 = 0.5;
+
+        let radius = RADIUS_RATIO * resolution.edge_length_rads();
+        let min_area = AREA_RATIO * resolution.area_rads2();
+        let perimeter = self.rings().map(Ring::perimeter_rads).sum::<f64>();
+        #[allow(clippy::cast_precision_loss)] // A handful of rings.
+        let ring_count = (self.interiors.len() + 1) as f64;
+
+        // The neighborhood of the outline: a band along each edge, plus a disk
+        // at both ends of every ring.
+        let outline =
+            (2. * radius).mul_add(perimeter, ring_count * PI * radius * radius);
+        let bound = ((self.area_rads2() + outline) / min_area).ceil();
+
+        // Truncate on purpose.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let bound = bound as usize;
+
+        bound
+    }
+
     /// Initialize a new polygon from a [`geo::Rect`] whose coordinates are in
-    /// radians.
+    /// radians, and at most a full turn wide.
     ///
     /// # Errors
     ///
@@ -99,8 +424,35 @@ impl<'a> Polygon<'a> {
     pub(super) fn from_rect(
         rect: geo::Rect<f64>,
     ) -> Result<Self, InvalidGeometry> {
-        let (exterior, interiors) = rect.to_polygon().into_inner();
-        debug_assert!(interiors.is_empty());
+        let (min, max) = (rect.min(), rect.max());
+        if !super::coord_is_valid(min) || !super::coord_is_valid(max) {
+            return Err(InvalidGeometry::new(
+                "invalid coordinate (e.g. infinite)",
+            ));
+        }
+
+        // Split the parallels in edges spanning at most 90 degrees, otherwise
+        // a wide rectangle would be mistaken for one crossing the antimeridian.
+        let width = max.x - min.x;
+        // Truncate on purpose, rectangles are at most a full turn wide.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let count = (width / FRAC_PI_2).ceil().max(1.) as usize;
+        #[allow(clippy::cast_precision_loss)] // At most 4 edges.
+        let lngs = (0..=count)
+            .map(|i| {
+                if i == count {
+                    max.x
+                } else {
+                    width.mul_add(i as f64 / count as f64, min.x)
+                }
+            })
+            .collect::<Vec<_>>();
+        let exterior = lngs
+            .iter()
+            .map(|&x| coord! { x: x, y: min.y })
+            .chain(lngs.iter().rev().map(|&x| coord! { x: x, y: max.y }))
+            .chain(std::iter::once(min))
+            .collect::<geo::LineString<f64>>();
 
         Ok(Self {
             exterior: Ring::from_radians(Cow::Owned(exterior))?,
@@ -140,7 +492,18 @@ impl<'a> Polygon<'a> {
         self.interiors.iter().map(Ring::geom)
     }
 
-    fn contains(&self, coord: Coord<f64>) -> bool {
+    /// Returns the rings of the polygon, exterior first.
+    pub(super) fn rings(&self) -> impl Iterator<Item = &Ring<'a>> {
+        std::iter::once(&self.exterior).chain(self.interiors.iter())
+    }
+
+    /// Returns the area of the polygon, in square radians.
+    fn area_rads2(&self) -> f64 {
+        let holes = self.interiors.iter().map(Ring::area_rads2).sum::<f64>();
+        (self.exterior.area_rads2() - holes).max(0.)
+    }
+
+    pub(super) fn contains(&self, coord: Coord<f64>) -> bool {
         self.exterior.contains(coord)
             && !self.interiors.iter().any(|ring| ring.contains(coord))
     }
@@ -262,8 +625,49 @@ impl ToCells for Polygon<'_> {
     }
 }
 
+impl Coverable for Polygon<'_> {}
+
+impl Regions for Polygon<'_> {
+    fn regions(&self, resolution: Resolution) -> Vec<Region<'_>> {
+        vec![Region::Edges(Classifier::new(self, resolution))]
+    }
+}
+
 // ----------------------------------------------------------------------------
 
+/// Adds the cells of the polygon descending from the given cell, compacted.
+///
+/// Returns true if the cell is entirely covered (and thus added as is).
+fn fill_compacted(
+    classifier: &Classifier<'_, '_>,
+    cell: CellIndex,
+    edges: &[usize],
+    cells: &mut Vec<CellIndex>,
+) -> bool {
+    match classifier.classify(cell, edges) {
+        Class::Inside => {
+            cells.push(cell);
+            true
+        }
+        Class::Outside => false,
+        Class::Boundary(edges) => {
+            let start = cells.len();
+            let resolution =
+                cell.resolution().succ().expect("finer resolution");
+            let mut is_full = true;
+            for child in cell.children(resolution) {
+                is_full &= fill_compacted(classifier, child, &edges, cells);
+            }
+            // Every child is covered: replace them by their parent.
+            if is_full {
+                cells.truncate(start);
+                cells.push(cell);
+            }
+            is_full
+        }
+    }
+}
+
 // Return the cell indexes that traces the ring outline.
 fn get_edge_cells(
     ring: &geo::LineString<f64>,
@@ -0,0 +1,165 @@
+use super::{
+    polyfill::{Class, Classifier},
+    Polygon,
+};
+use crate::{CellIndex, LatLng, Resolution};
+use ahash::HashMap;
+use geo::coord;
+use std::rc::Rc;
+
+/// An index of polygons, to find the polygons containing a point.
+///
+/// Every polygon is broken down into cells: those entirely inside the polygon
+/// answer a lookup directly, only the cells crossed by the outline of the
+/// polygon require an exact point-in-polygon test.
+///
+/// Cells entirely inside a polygon are stored compacted, so the resolution
+/// mostly drives the number of boundary cells: a finer resolution makes the
+/// exact tests rarer, at the expense of memory.
+#[derive(Debug, Clone)]
+pub struct PolygonIndex<'a, T> {
+    /// Resolution of the boundary cells.
+    resolution: Resolution,
+    /// Indexed polygons, with their ID.
+    polygons: Vec<(T, Polygon<'a>)>,
+    /// Polygons overlapping each cell.
+    cells: HashMap<CellIndex, Vec<Entry>>,
+}
+
+impl<'a, T> PolygonIndex<'a, T> {
+    /// Initializes a new, empty, index at the given resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::PolygonIndex, Resolution};
+    ///
+    /// let index = PolygonIndex::<u32>::new(Resolution::Seven);
+    /// ```
+    #[must_use]
+    pub fn new(resolution: Resolution) -> Self {
+        Self {
+            resolution,
+            polygons: Vec::new(),
+            cells: HashMap::default(),
+        }
+    }
+
+    /// Returns the number of polygons in the index.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.polygons.len()
+    }
+
+    /// Returns true if the index contains no polygon.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.polygons.is_empty()
+    }
+
+    /// Inserts a polygon, with its ID, into the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::{Polygon, PolygonIndex}, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 2.2, y: 48.8), (x: 2.4, y: 48.8), (x: 2.4, y: 48.9),
+    ///     (x: 2.2, y: 48.9), (x: 2.2, y: 48.8),
+    /// ];
+    /// let mut index = PolygonIndex::new(Resolution::Seven);
+    /// index.insert("Paris", Polygon::from_degrees(p)?);
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    pub fn insert(&mut self, id: T, polygon: Polygon<'a>) {
+        let position = self.polygons.len();
+        let classifier = Classifier::areal(&polygon, self.resolution);
+        let edges = Rc::<[usize]>::from(classifier.edges());
+        let mut stack = CellIndex::base_cells()
+            .map(|cell| (cell, Rc::clone(&edges)))
+            .collect::<Vec<_>>();
+
+        while let Some((cell, edges)) = stack.pop() {
+            let is_interior = match classifier.classify(cell, &edges) {
+                Class::Inside => true,
+                Class::Outside => continue,
+                Class::Boundary(edges) => {
+                    if let Some(resolution) = cell.resolution().succ() {
+                        if resolution <= self.resolution {
+                            let edges = Rc::<[usize]>::from(edges);
+                            stack.extend(
+                                cell.children(resolution)
+                                    .map(|child| (child, Rc::clone(&edges))),
+                            );
+                            continue;
+                        }
+                    }
+                    false
+                }
+            };
+            self.cells.entry(cell).or_default().push(Entry {
+                position,
+                is_interior,
+            });
+        }
+
+        self.polygons.push((id, polygon));
+    }
+
+    /// Returns the IDs of the polygons containing the point.
+    ///
+    /// IDs are returned in insertion order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{geom::{Polygon, PolygonIndex}, LatLng, Resolution};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 2.2, y: 48.8), (x: 2.4, y: 48.8), (x: 2.4, y: 48.9),
+    ///     (x: 2.2, y: 48.9), (x: 2.2, y: 48.8),
+    /// ];
+    /// let mut index = PolygonIndex::new(Resolution::Seven);
+    /// index.insert("Paris", Polygon::from_degrees(p)?);
+    ///
+    /// let point = LatLng::new(48.864716, 2.349014).expect("valid coordinate");
+    /// assert_eq!(index.lookup(point), vec![&"Paris"]);
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn lookup(&self, point: LatLng) -> Vec<&T> {
+        let coord = coord! { x: point.lng_radians(), y: point.lat_radians() };
+        let cell = point.to_cell(self.resolution);
+        let mut positions =
+            Resolution::range(Resolution::Zero, self.resolution)
+                .filter_map(|resolution| cell.parent(resolution))
+                .filter_map(|ancestor| self.cells.get(&ancestor))
+                .flatten()
+                .filter(|entry| {
+                    entry.is_interior
+                        || self.polygons[entry.position].1.contains(coord)
+                })
+                .map(|entry| entry.position)
+                .collect::<Vec<_>>();
+        positions.sort_unstable();
+
+        positions
+            .into_iter()
+            .map(|position| &self.polygons[position].0)
+            .collect()
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// A polygon overlapping a cell.
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    /// Position of the polygon in the index.
+    position: usize,
+    /// Whether the cell is entirely inside the polygon.
+    is_interior: bool,
+}
@@ -1,22 +1,40 @@
+use super::{polyfill::Region, MultiPolygon};
 use crate::{
     error::InvalidGeometry,
-    geom::{Polygon, ToCells},
-    CellIndex, Resolution,
+    geom::{Coverable, Polygon, Regions, ToCells},
+    CellIndex, Resolution, TWO_PI,
 };
-use std::boxed::Box;
+use geo::Coord;
+use std::{boxed::Box, f64::consts::PI};
 
 /// An axis-aligned bounded 2D rectangle whose area is defined by minimum and
 /// maximum [`geo::Coord`]s.
+///
+/// A rectangle whose western longitude is greater than its eastern one crosses
+/// the antimeridian (see [`Self::from_corners_radians`]). Once converted back
+/// into a [`geo::Rect`], its eastern longitude is then shifted by a full turn
+/// (i.e. greater than 180 degrees).
 #[derive(Clone, Debug, PartialEq)]
-pub struct Rect<'a>(Polygon<'a>);
+pub struct Rect<'a> {
+    /// Bounds of the rectangle, the eastern longitude is shifted by a full
+    /// turn when crossing the antimeridian.
+    bbox: geo::Rect<f64>,
+    /// Polygons covering the rectangle (two when crossing the antimeridian).
+    polygons: MultiPolygon<'a>,
+}
 
 impl Rect<'_> {
     /// Initialize a new rectangle from a rect whose coordinates are in radians.
     ///
+    /// As [`geo::Rect`] reorders its corners, the rectangle can't cross the
+    /// antimeridian unless its eastern longitude is shifted by a full turn
+    /// (as done when converting a [`Rect`] back into a [`geo::Rect`]): use
+    /// [`Self::from_corners_radians`] otherwise.
+    ///
     /// # Errors
     ///
     /// [`InvalidGeometry`] if the rectangle is invalid (e.g. contains
-    /// non-finite coordinates).
+    /// non-finite coordinates, or is wider than a full turn).
     ///
     /// # Example
     ///
@@ -31,15 +49,20 @@ impl Rect<'_> {
     /// # Ok::<(), h3o::error::InvalidGeometry>(())
     /// ```
     pub fn from_radians(rect: geo::Rect<f64>) -> Result<Self, InvalidGeometry> {
-        Ok(Self(Polygon::from_rect(rect)?))
+        Self::from_corners_radians(rect.min(), rect.max())
     }
 
     /// Initialize a new rectangle from a rect whose coordinates are in degrees.
     ///
+    /// As [`geo::Rect`] reorders its corners, the rectangle can't cross the
+    /// antimeridian unless its eastern longitude is shifted by a full turn
+    /// (i.e. greater than 180 degrees): use [`Self::from_corners_degrees`]
+    /// otherwise.
+    ///
     /// # Errors
     ///
     /// [`InvalidGeometry`] if the rectangle is invalid (e.g. contains
-    /// non-finite coordinates).
+    /// non-finite coordinates, or is wider than a full turn).
     ///
     /// # Example
     ///
@@ -54,25 +77,192 @@ impl Rect<'_> {
     /// # Ok::<(), h3o::error::InvalidGeometry>(())
     /// ```
     pub fn from_degrees(rect: geo::Rect<f64>) -> Result<Self, InvalidGeometry> {
-        Ok(Self(Polygon::from_degrees(rect.to_polygon())?))
+        Self::from_corners_degrees(rect.min(), rect.max())
+    }
+
+    /// Initialize a new rectangle from its south-west and north-east corners,
+    /// whose coordinates are in radians.
+    ///
+    /// Unlike [`geo::Rect`], the corners are not reordered: a western
+    /// longitude greater than the eastern one describes a rectangle crossing
+    /// the antimeridian.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidGeometry`] if the rectangle is invalid (e.g. contains
+    /// non-finite coordinates, or is wider than a full turn).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::geom::Rect;
+    ///
+    /// // From 170°E to 170°W.
+    /// let rect = Rect::from_corners_radians(
+    ///    geo::coord! { x: 2.9670597283903604, y: -0.17453292519943295 },
+    ///    geo::coord! { x: -2.9670597283903604, y: 0.17453292519943295 },
+    /// )?;
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    pub fn from_corners_radians(
+        south_west: Coord,
+        north_east: Coord,
+    ) -> Result<Self, InvalidGeometry> {
+        let (west, east) = (south_west.x, north_east.x);
+        let (south, north) = if south_west.y < north_east.y {
+            (south_west.y, north_east.y)
+        } else {
+            (north_east.y, south_west.y)
+        };
+        let rect = |west, east| {
+            Polygon::from_rect(geo::Rect::new(
+                geo::coord! { x: west, y: south },
+                geo::coord! { x: east, y: north },
+            ))
+        };
+
+        // Longitudes are normalized: the rectangle spans `width` eastward
+        // from `west`.
+        let width = if west > east {
+            (east - west).rem_euclid(TWO_PI)
+        } else {
+            east - west
+        };
+        if width > TWO_PI {
+            return Err(InvalidGeometry::new(
+                "rectangle wider than a full turn",
+            ));
+        }
+        let west = (west + PI).rem_euclid(TWO_PI) - PI;
+        let east = west + width;
+
+        // Split at the antimeridian, each side is a regular rectangle.
+        let polygons = if east > PI {
+            vec![rect(west, PI)?, rect(-PI, east - TWO_PI)?]
+        } else {
+            vec![rect(west, east)?]
+        };
+
+        Ok(Self {
+            bbox: geo::Rect::new(
+                geo::coord! { x: west, y: south },
+                geo::coord! { x: east, y: north },
+            ),
+            polygons: MultiPolygon::from_polygons(polygons),
+        })
+    }
+
+    /// Initialize a new rectangle from its south-west and north-east corners,
+    /// whose coordinates are in degrees.
+    ///
+    /// Unlike [`geo::Rect`], the corners are not reordered: a western
+    /// longitude greater than the eastern one describes a rectangle crossing
+    /// the antimeridian.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidGeometry`] if the rectangle is invalid (e.g. contains
+    /// non-finite coordinates, or is wider than a full turn).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::geom::Rect;
+    ///
+    /// // From 170°E to 170°W.
+    /// let rect = Rect::from_corners_degrees(
+    ///    geo::coord! { x: 170., y: -10. },
+    ///    geo::coord! { x: -170., y: 10. },
+    /// )?;
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    pub fn from_corners_degrees(
+        south_west: Coord,
+        north_east: Coord,
+    ) -> Result<Self, InvalidGeometry> {
+        Self::from_corners_radians(
+            geo::coord! {
+                x: south_west.x.to_radians(),
+                y: south_west.y.to_radians(),
+            },
+            geo::coord! {
+                x: north_east.x.to_radians(),
+                y: north_east.y.to_radians(),
+            },
+        )
+    }
+
+    /// Returns an estimation of the number of cells returned by
+    /// [`ToCells::to_cells`], based on the area of the rectangle.
+    ///
+    /// See [`Polygon::estimate_cells_count`] for the error bound.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::Rect, Resolution};
+    ///
+    /// let rect = geo::Rect::new(
+    ///    geo::coord! { x: 103.61113510075143, y: 1.19558156826659 },
+    ///    geo::coord! { x: 104.0613068942643,  y: 1.473553156420067 },
+    /// );
+    /// let rect = Rect::from_degrees(rect)?;
+    /// let count = rect.estimate_cells_count(Resolution::Nine);
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn estimate_cells_count(&self, resolution: Resolution) -> usize {
+        self.polygons.estimate_cells_count(resolution)
+    }
+
+    /// Returns an upper bound to the number of cells returned by
+    /// [`ToCells::to_cells`], based on the area and the perimeter of the
+    /// rectangle.
+    ///
+    /// See [`Polygon::cells_count_upper_bound`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::Rect, Resolution};
+    ///
+    /// let rect = geo::Rect::new(
+    ///    geo::coord! { x: 103.61113510075143, y: 1.19558156826659 },
+    ///    geo::coord! { x: 104.0613068942643,  y: 1.473553156420067 },
+    /// );
+    /// let rect = Rect::from_degrees(rect)?;
+    /// let bound = rect.cells_count_upper_bound(Resolution::Nine);
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn cells_count_upper_bound(&self, resolution: Resolution) -> usize {
+        self.polygons.cells_count_upper_bound(resolution)
     }
 }
 
 impl From<Rect<'_>> for geo::Rect<f64> {
     fn from(value: Rect<'_>) -> Self {
-        value.0.bbox()
+        value.bbox
     }
 }
 
 impl ToCells for Rect<'_> {
     fn max_cells_count(&self, resolution: Resolution) -> usize {
-        self.0.max_cells_count(resolution)
+        self.polygons.max_cells_count(resolution)
     }
 
     fn to_cells(
         &self,
         resolution: Resolution,
     ) -> Box<dyn Iterator<Item = CellIndex> + '_> {
-        self.0.to_cells(resolution)
+        self.polygons.to_cells(resolution)
+    }
+}
+
+impl Coverable for Rect<'_> {}
+
+impl Regions for Rect<'_> {
+    fn regions(&self, resolution: Resolution) -> Vec<Region<'_>> {
+        self.polygons.regions(resolution)
     }
 }
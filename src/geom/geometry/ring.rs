@@ -1,13 +1,15 @@
-use super::bbox;
+use super::{bbox, edge_index::EdgeIndex};
 use crate::{error::InvalidGeometry, TWO_PI};
 use geo::{Coord, Intersects};
 use std::{borrow::Cow, f64::consts::PI};
 
-/// A closed ring and its bounding box.
+/// A closed ring, its bounding box and, for large rings, an index of its
+/// edges.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Ring<'a> {
     geom: Cow<'a, geo::LineString<f64>>,
     bbox: geo::Rect<f64>,
+    index: Option<EdgeIndex>,
 }
 
 impl<'a> Ring<'a> {
@@ -17,8 +19,13 @@ impl<'a> Ring<'a> {
         ring: Cow<'a, geo::LineString<f64>>,
     ) -> Result<Self, InvalidGeometry> {
         let bbox = bbox::compute_from_ring(&ring)?;
+        let index = EdgeIndex::new(&ring);
 
-        Ok(Self { geom: ring, bbox })
+        Ok(Self {
+            geom: ring,
+            bbox,
+            index,
+        })
     }
 
     /// Initialize a new ring from a closed `geo::LineString` whose coordinates
@@ -34,8 +41,9 @@ impl<'a> Ring<'a> {
             Cow::Owned(ring)
         };
         let bbox = bbox::compute_from_ring(&geom)?;
+        let index = EdgeIndex::new(&geom);
 
-        Ok(Self { geom, bbox })
+        Ok(Self { geom, bbox, index })
     }
 
     pub fn geom(&self) -> &geo::LineString<f64> {
@@ -46,15 +54,61 @@ impl<'a> Ring<'a> {
         self.bbox
     }
 
-    // Those strict comparisons are done on purpose.
-    #[allow(clippy::float_cmp)]
-    pub fn contains(&self, mut coord: Coord<f64>) -> bool {
-        // Use the ray-tracing algorithm: count #times a
-        // horizontal ray from point (to positive infinity).
-        //
-        // See: https://en.wikipedia.org/wiki/Point_in_polygon
+    /// Returns true if the ring crosses the antimeridian, in which case its
+    /// negative longitudes are shifted by a full turn.
+    pub fn is_transmeridian(&self) -> bool {
+        self.bbox.max().x > PI
+    }
+
+    /// Returns the area of the ring, in square radians.
+    ///
+    /// Edges are straight lines in the longitude/latitude space (as for the
+    /// containment test), the area of the enclosed region on the unit sphere
+    /// is computed exactly.
+    pub fn area_rads2(&self) -> f64 {
+        // Green's theorem on the spherical area element: the area is the
+        // integral of `-sin(lat)` along the ring, w.r.t. the longitude.
+        self.geom
+            .lines()
+            .map(|line| {
+                let half = line.dy() / 2.;
+                let mid = line.start.y + half;
+                // Integral of `sin(lat)` along the edge, divided by `dlng`.
+                let factor = if half == 0. {
+                    mid.sin()
+                } else {
+                    mid.sin() * half.sin() / half
+                };
+                -wrap_dlng(line.dx()) * factor
+            })
+            .sum::<f64>()
+            .abs()
+    }
+
+    /// Returns an upper bound of the perimeter of the ring, in radians.
+    pub fn perimeter_rads(&self) -> f64 {
+        self.geom
+            .lines()
+            .map(|line| {
+                // The east-west extent is the widest at the latitude closest
+                // to the equator.
+                let (min, max) = if line.start.y < line.end.y {
+                    (line.start.y, line.end.y)
+                } else {
+                    (line.end.y, line.start.y)
+                };
+                let cos = if min <= 0. && max >= 0. {
+                    1.
+                } else {
+                    min.cos().max(max.cos())
+                };
+                (wrap_dlng(line.dx()) * cos).hypot(line.dy())
+            })
+            .sum()
+    }
 
-        let is_transmeridian = self.bbox.max().x > PI;
+    pub fn contains(&self, mut coord: Coord<f64>) -> bool {
+        let is_transmeridian = self.is_transmeridian();
         if is_transmeridian {
             coord.x += f64::from(u8::from(coord.x < 0.)) * TWO_PI;
         }
@@ -63,63 +117,102 @@ impl<'a> Ring<'a> {
             return false;
         }
 
-        let mut contains = false;
-        for geo::Line { mut start, mut end } in self.geom.lines() {
-            // Ray casting algo requires the second point to always be higher
-            // than the first, so swap if needed.
-            if start.y > end.y {
-                (start, end) = (end, start);
-            }
+        // Only the edges spanning the latitude of the point can be crossed by
+        // the ray, the others are skipped without side effects.
+        self.index.as_ref().map_or_else(
+            || ray_cast(coord, self.geom.lines(), is_transmeridian),
+            |index| {
+                let coords = &self.geom.0;
+                let lines = index
+                    .candidates(coord.y)
+                    .map(|i| geo::Line::new(coords[i], coords[i + 1]));
+                ray_cast(coord, lines, is_transmeridian)
+            },
+        )
+    }
+}
 
-            // If the latitude matches exactly, we'll hit an edge case where the
-            // ray passes through the vertex twice on successive segment checks.
-            // To avoid this, adjust the latitude northward if needed.
-            //
-            // NOTE: This currently means that a point at the north pole cannot
-            // be contained in any polygon. This is acceptable in current usage,
-            // because the point we test in this function at present is always a
-            // cell center or vertex, and no cell has a center or vertex on the
-            // north pole. If we need to expand this algo to more generic uses
-            // we might need to handle this edge case.
-            if coord.y == start.y || coord.y == end.y {
-                coord.y += f64::EPSILON;
-            }
+/// Returns the longitudinal extent of an edge, going through the antimeridian
+/// for arcs larger than 180 degrees.
+fn wrap_dlng(dlng: f64) -> f64 {
+    if dlng > PI {
+        dlng - TWO_PI
+    } else if dlng < -PI {
+        dlng + TWO_PI
+    } else {
+        dlng
+    }
+}
 
-            // If we're totally above or below the latitude ranges, the test ray
-            // cannot intersect the line segment, so let's move on.
-            if coord.y < start.y || coord.y > end.y {
-                continue;
-            }
+/// Returns true if the point is inside the ring made of the given edges.
+// Those strict comparisons are done on purpose.
+#[allow(clippy::float_cmp)]
+fn ray_cast(
+    mut coord: Coord<f64>,
+    lines: impl Iterator<Item = geo::Line<f64>>,
+    is_transmeridian: bool,
+) -> bool {
+    // Use the ray-tracing algorithm: count #times a
+    // horizontal ray from point (to positive infinity).
+    //
+    // See: https://en.wikipedia.org/wiki/Point_in_polygon
+
+    let mut contains = false;
+    for geo::Line { mut start, mut end } in lines {
+        // Ray casting algo requires the second point to always be higher
+        // than the first, so swap if needed.
+        if start.y > end.y {
+            (start, end) = (end, start);
+        }
 
-            if is_transmeridian {
-                start.x += f64::from(u8::from(start.x < 0.)) * TWO_PI;
-                end.x += f64::from(u8::from(end.x < 0.)) * TWO_PI;
-            }
+        // If the latitude matches exactly, we'll hit an edge case where the
+        // ray passes through the vertex twice on successive segment checks.
+        // To avoid this, adjust the latitude northward if needed.
+        //
+        // NOTE: This currently means that a point at the north pole cannot
+        // be contained in any polygon. This is acceptable in current usage,
+        // because the point we test in this function at present is always a
+        // cell center or vertex, and no cell has a center or vertex on the
+        // north pole. If we need to expand this algo to more generic uses
+        // we might need to handle this edge case.
+        if coord.y == start.y || coord.y == end.y {
+            coord.y += f64::EPSILON;
+        }
 
-            // Rays are cast in the longitudinal direction, in case a point
-            // exactly matches, to decide tiebreakers, bias westerly.
-            if start.x == coord.x || end.x == coord.x {
-                coord.x -= f64::EPSILON;
-            }
+        // If we're totally above or below the latitude ranges, the test ray
+        // cannot intersect the line segment, so let's move on.
+        if coord.y < start.y || coord.y > end.y {
+            continue;
+        }
 
-            // For the latitude of the point, compute the longitude of the
-            // point that lies on the line segment defined by `a` and `b`
-            // This is done by computing the percent above `a` the lat is,
-            // and traversing the same percent in the longitudinal direction
-            // of `a` to `b`.
-            let ratio = (coord.y - start.y) / (end.y - start.y);
-            let mut test_lng = (end.x - start.x).mul_add(ratio, start.x);
-            test_lng +=
-                f64::from(u8::from(is_transmeridian && test_lng < 0.)) * TWO_PI;
-
-            // Intersection of the ray
-            if test_lng > coord.x {
-                contains = !contains;
-            }
+        if is_transmeridian {
+            start.x += f64::from(u8::from(start.x < 0.)) * TWO_PI;
+            end.x += f64::from(u8::from(end.x < 0.)) * TWO_PI;
+        }
+
+        // Rays are cast in the longitudinal direction, in case a point
+        // exactly matches, to decide tiebreakers, bias westerly.
+        if start.x == coord.x || end.x == coord.x {
+            coord.x -= f64::EPSILON;
         }
 
-        contains
+        // For the latitude of the point, compute the longitude of the
+        // point that lies on the line segment defined by `a` and `b`
+        // This is done by computing the percent above `a` the lat is,
+        // and traversing the same percent in the longitudinal direction
+        // of `a` to `b`.
+        let ratio = (coord.y - start.y) / (end.y - start.y);
+        let mut test_lng = (end.x - start.x).mul_add(ratio, start.x);
+        test_lng +=
+            f64::from(u8::from(is_transmeridian && test_lng < 0.)) * TWO_PI;
+
+        // Intersection of the ray
+        if test_lng > coord.x {
+            contains = !contains;
+        }
     }
+
+    contains
 }
 
 impl From<Ring<'_>> for geo::LineString<f64> {
@@ -127,3 +220,7 @@ impl From<Ring<'_>> for geo::LineString<f64> {
         value.geom.into_owned()
     }
 }
+
+#[cfg(test)]
+#[path = "./ring_tests.rs"]
+mod tests;
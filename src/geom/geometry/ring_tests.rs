@@ -0,0 +1,107 @@
+use super::*;
+use geo::coord;
+
+/// A jagged ring of `count` vertices around the given center (in radians).
+fn jagged_ring(lng: f64, lat: f64, count: usize) -> geo::LineString<f64> {
+    let mut coords = (0..count)
+        .map(|i| {
+            #[allow(clippy::cast_precision_loss)] // Small test values.
+            let (i, count) = (i as f64, count as f64);
+            let angle = TWO_PI * i / count;
+            let radius = 0.03_f64
+                .mul_add((17. * angle).sin(), 0.01_f64.mul_add(i % 3., 0.1));
+            let x = radius.mul_add(angle.cos(), lng);
+            let x = if x > PI { x - TWO_PI } else { x };
+            coord! { x: x, y: radius.mul_add(angle.sin(), lat) }
+        })
+        .collect::<Vec<_>>();
+    coords.push(coords[0]);
+    geo::LineString::new(coords)
+}
+
+/// Checks that the indexed test matches the exhaustive one.
+fn check(ring: &geo::LineString<f64>) {
+    let ring = Ring::from_radians(Cow::Borrowed(ring)).expect("ring");
+    assert!(ring.index.is_some());
+    let mut naive = ring.clone();
+    naive.index = None;
+
+    let bbox = ring.bbox();
+    let (min, max) = (bbox.min(), bbox.max());
+    let mut points = Vec::new();
+    for i in 0..=100 {
+        for j in 0..=100 {
+            let x = min.x + (max.x - min.x) * f64::from(i) / 100.;
+            let y = min.y + (max.y - min.y) * f64::from(j) / 100.;
+            points
+                .push(coord! { x: if x > PI { x - TWO_PI } else { x }, y: y });
+        }
+    }
+    // Vertices, and points aligned with them, hit the tie-breaking rules.
+    for vertex in ring.geom().coords() {
+        points.push(*vertex);
+        points.push(coord! { x: vertex.x - 0.01, y: vertex.y });
+        points.push(coord! { x: vertex.x + 0.01, y: vertex.y });
+    }
+
+    let mut inside = 0;
+    for point in points {
+        let expected = naive.contains(point);
+        assert_eq!(ring.contains(point), expected, "{point:?}");
+        inside += usize::from(expected);
+    }
+    assert!(inside > 0);
+}
+
+#[test]
+fn indexed_contains() {
+    check(&jagged_ring(0.5, 0.8, 1000));
+}
+
+#[test]
+fn indexed_contains_transmeridian() {
+    check(&jagged_ring(PI, -0.2, 1000));
+}
+
+#[test]
+fn small_ring_not_indexed() {
+    let ring = jagged_ring(0.5, 0.8, 10);
+    let ring = Ring::from_radians(Cow::Borrowed(&ring)).expect("ring");
+
+    assert!(ring.index.is_none());
+}
+
+#[test]
+fn area_and_perimeter() {
+    // Between the equator and 30°N, over a quarter of the globe.
+    let ring = geo::LineString::new(vec![
+        coord! { x: 0., y: 0. },
+        coord! { x: PI / 2., y: 0. },
+        coord! { x: PI / 2., y: PI / 6. },
+        coord! { x: 0., y: PI / 6. },
+        coord! { x: 0., y: 0. },
+    ]);
+    let ring = Ring::from_radians(Cow::Borrowed(&ring)).expect("ring");
+
+    // Zone area is `dlng × (sin(north) - sin(south))`.
+    assert!((ring.area_rads2() - PI / 4.).abs() < 1e-12);
+    // Both parallels are measured at their own latitude.
+    let perimeter = (PI / 2.).mul_add((PI / 6.).cos(), PI / 3. + PI / 2.);
+    assert!((ring.perimeter_rads() - perimeter).abs() < 1e-12);
+}
+
+#[test]
+fn area_transmeridian() {
+    let ring = geo::LineString::new(vec![
+        coord! { x: PI - 0.1, y: -0.1 },
+        coord! { x: -PI + 0.1, y: -0.1 },
+        coord! { x: -PI + 0.1, y: 0.1 },
+        coord! { x: PI - 0.1, y: 0.1 },
+        coord! { x: PI - 0.1, y: -0.1 },
+    ]);
+    let ring = Ring::from_radians(Cow::Borrowed(&ring)).expect("ring");
+
+    // Spans 0.2 radians of longitude, between ±0.1 of latitude.
+    let expected = 0.2 * 2. * 0.1_f64.sin();
+    assert!((ring.area_rads2() - expected).abs() < 1e-12);
+}
@@ -0,0 +1,41 @@
+use crate::{geom::ToCells, CellIndex, Resolution};
+use std::{boxed::Box, cmp::Reverse, collections::BinaryHeap};
+
+/// A sorted stream of cells.
+pub type SortedCells<'a> = Box<dyn Iterator<Item = CellIndex> + 'a>;
+
+/// Returns the cells of the geometry, sorted and without duplicates.
+pub fn sort<'a>(
+    geometry: &impl ToCells,
+    resolution: Resolution,
+) -> SortedCells<'a> {
+    let mut cells = geometry.to_cells(resolution).collect::<Vec<_>>();
+    cells.sort_unstable();
+    cells.dedup();
+    Box::new(cells.into_iter())
+}
+
+/// Merges sorted streams of cells into a single one, without duplicates.
+pub fn merge<'a>(
+    streams: impl IntoIterator<Item = SortedCells<'a>>,
+) -> impl Iterator<Item = CellIndex> + 'a {
+    let mut streams = streams.into_iter().collect::<Vec<_>>();
+    // Next cell of every stream.
+    let mut heads = streams
+        .iter_mut()
+        .enumerate()
+        .filter_map(|(i, stream)| stream.next().map(|cell| Reverse((cell, i))))
+        .collect::<BinaryHeap<_>>();
+    let mut last = None;
+
+    std::iter::from_fn(move || loop {
+        let Reverse((cell, i)) = heads.pop()?;
+        if let Some(next) = streams[i].next() {
+            heads.push(Reverse((next, i)));
+        }
+        if last != Some(cell) {
+            last = Some(cell);
+            return Some(cell);
+        }
+    })
+}
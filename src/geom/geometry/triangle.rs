@@ -1,6 +1,7 @@
+use super::polyfill::Region;
 use crate::{
     error::InvalidGeometry,
-    geom::{Polygon, ToCells},
+    geom::{Coverable, Polygon, Regions, ToCells},
     CellIndex, Resolution,
 };
 use geo::CoordsIter;
@@ -88,3 +89,11 @@ impl ToCells for Triangle<'_> {
         self.0.to_cells(resolution)
     }
 }
+
+impl Coverable for Triangle<'_> {}
+
+impl Regions for Triangle<'_> {
+    fn regions(&self, resolution: Resolution) -> Vec<Region<'_>> {
+        self.0.regions(resolution)
+    }
+}
@@ -0,0 +1,220 @@
+use super::{MultiPolygon, Polygon};
+use crate::{error::InvalidGeometry, CellIndex, Resolution};
+use std::collections::BTreeMap;
+
+/// Kind of a numeric attribute, which drives how it's redistributed onto cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Attribute {
+    /// Value that scales with the area (e.g. a population count).
+    ///
+    /// The value of a feature is split between the cells, proportionally to
+    /// the area they share with the feature.
+    Extensive,
+    /// Value that doesn't scale with the area (e.g. a density, a rate).
+    ///
+    /// The value of a cell is the average of the values of the features it
+    /// overlaps, weighted by the overlapping area.
+    Intensive,
+}
+
+/// A polygonal feature with numeric attributes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Feature<'a> {
+    geometry: MultiPolygon<'a>,
+    values: Vec<f64>,
+}
+
+impl<'a> Feature<'a> {
+    /// Initializes a new feature from its geometry and its attribute values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::geom::{Feature, Polygon};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 37.58601939796671, y: 55.72992682544245),
+    ///     (x: 37.66530173673016, y: 55.72992682544245),
+    ///     (x: 37.66530173673016, y: 55.777641325418415),
+    ///     (x: 37.58601939796671, y: 55.777641325418415),
+    ///     (x: 37.58601939796671, y: 55.72992682544245),
+    /// ];
+    /// let polygon = Polygon::from_degrees(p)?;
+    /// let feature = Feature::new(polygon.into(), vec![1200., 0.25]);
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub const fn new(geometry: MultiPolygon<'a>, values: Vec<f64>) -> Self {
+        Self { geometry, values }
+    }
+
+    /// Initializes a new feature from a `GeoJSON` feature.
+    ///
+    /// The values are read from the properties with the given names, missing
+    /// or non-numeric properties are set to `NaN`.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidGeometry`] if the feature has no geometry, a non-polygonal
+    /// geometry or an invalid one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::geom::Feature;
+    ///
+    /// let json = r#"{
+    ///   "type": "Feature",
+    ///   "properties": { "population": 1200, "density": 0.25 },
+    ///   "geometry": {
+    ///     "type": "Polygon",
+    ///     "coordinates": [[
+    ///       [37.58, 55.72], [37.66, 55.72], [37.66, 55.77], [37.58, 55.72]
+    ///     ]]
+    ///   }
+    /// }"#;
+    /// let feature = json.parse::<geojson::Feature>()?;
+    /// let feature = Feature::from_geojson(&feature, &["population", "density"])?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_geojson(
+        feature: &geojson::Feature,
+        names: &[&str],
+    ) -> Result<Self, InvalidGeometry> {
+        let geometry = feature
+            .geometry
+            .as_ref()
+            .ok_or_else(|| InvalidGeometry::new("geometryless feature"))?;
+        let geometry = match geometry.value {
+            geojson::Value::Polygon(_) => {
+                geo::Polygon::try_from(&geometry.value)
+                    .map_err(|_| InvalidGeometry::new("invalid polygon"))
+                    .and_then(Polygon::from_degrees)?
+                    .into()
+            }
+            geojson::Value::MultiPolygon(_) => {
+                geo::MultiPolygon::try_from(&geometry.value)
+                    .map_err(|_| InvalidGeometry::new("invalid multipolygon"))
+                    .and_then(MultiPolygon::from_degrees)?
+            }
+            _ => return Err(InvalidGeometry::new("non-polygonal feature")),
+        };
+        let values = names
+            .iter()
+            .map(|name| {
+                feature
+                    .property(name)
+                    .and_then(geojson::JsonValue::as_f64)
+                    .unwrap_or(f64::NAN)
+            })
+            .collect();
+
+        Ok(Self { geometry, values })
+    }
+}
+
+/// Redistributes the attributes of polygonal features onto the cells, at the
+/// given resolution (a.k.a. areal interpolation).
+///
+/// `attributes` describes the values of the features: the i-th value of every
+/// feature is interpolated according to the i-th attribute kind (missing and
+/// `NaN` values are ignored).
+///
+/// Returns the interpolated values for every cell overlapping a feature, in
+/// the same order as `attributes`. Extensive values sum the contributions of
+/// every feature (the totals are preserved), intensive values are the
+/// area-weighted average of the overlapping features (`NaN` if no feature
+/// provided a value).
+///
+/// # Example
+///
+/// ```
+/// use geo::polygon;
+/// use h3o::{
+///     geom::{areal_interpolation, Attribute, Feature, Polygon},
+///     Resolution,
+/// };
+///
+/// let p: geo::Polygon<f64> = polygon![
+///     (x: 37.58601939796671, y: 55.72992682544245),
+///     (x: 37.66530173673016, y: 55.72992682544245),
+///     (x: 37.66530173673016, y: 55.777641325418415),
+///     (x: 37.58601939796671, y: 55.777641325418415),
+///     (x: 37.58601939796671, y: 55.72992682544245),
+/// ];
+/// let feature = Feature::new(Polygon::from_degrees(p)?.into(), vec![1200., 0.25]);
+/// let cells = areal_interpolation(
+///     &[feature],
+///     &[Attribute::Extensive, Attribute::Intensive],
+///     Resolution::Eight,
+/// );
+/// let population = cells.values().map(|values| values[0]).sum::<f64>();
+/// assert!((population - 1200.).abs() < 1e-6);
+/// # Ok::<(), h3o::error::InvalidGeometry>(())
+/// ```
+#[must_use]
+pub fn areal_interpolation(
+    features: &[Feature<'_>],
+    attributes: &[Attribute],
+    resolution: Resolution,
+) -> BTreeMap<CellIndex, Vec<f64>> {
+    // Per cell, the sums of the values and of the weights (for the intensive
+    // attributes).
+    let mut cells = BTreeMap::<_, (Vec<f64>, Vec<f64>)>::new();
+
+    for feature in features {
+        let overlaps = feature
+            .geometry
+            .coverage(resolution)
+            .into_iter()
+            .map(|(cell, fraction)| (cell, fraction * cell.area_rads2()))
+            .collect::<Vec<_>>();
+        // Use the covered area (rather than the area of the feature) to make
+        // sure that extensive values are preserved.
+        let total = overlaps.iter().map(|&(_, area)| area).sum::<f64>();
+        if total == 0. {
+            continue;
+        }
+
+        for (cell, area) in overlaps {
+            let &mut (ref mut values, ref mut weights) =
+                cells.entry(cell).or_insert_with(|| {
+                    (vec![0.; attributes.len()], vec![0.; attributes.len()])
+                });
+            for (i, attribute) in attributes.iter().enumerate() {
+                let Some(&value) =
+                    feature.values.get(i).filter(|value| !value.is_nan())
+                else {
+                    continue;
+                };
+                match *attribute {
+                    Attribute::Extensive => {
+                        values[i] += value * area / total;
+                    }
+                    Attribute::Intensive => {
+                        values[i] += value * area;
+                        weights[i] += area;
+                    }
+                }
+            }
+        }
+    }
+
+    cells
+        .into_iter()
+        .map(|(cell, (mut values, weights))| {
+            for (i, attribute) in attributes.iter().enumerate() {
+                if *attribute == Attribute::Intensive {
+                    values[i] = if weights[i] > 0. {
+                        values[i] / weights[i]
+                    } else {
+                        f64::NAN
+                    };
+                }
+            }
+            (cell, values)
+        })
+        .collect()
+}
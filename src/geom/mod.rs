@@ -22,19 +22,28 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
+mod coverer;
+mod edge_set;
 mod geometry;
+mod interpolation;
 mod json;
 mod ring_hierarchy;
+mod simplify;
 mod to_geo;
 mod to_h3;
 mod vertex_graph;
 
+use coverer::Regions;
 use ring_hierarchy::RingHierarchy;
 use vertex_graph::VertexGraph;
 
+pub use coverer::{Coverable, Coverer};
+pub use edge_set::EdgeSet;
 pub use geometry::{
-    Geometry, GeometryCollection, Line, LineString, MultiLineString,
-    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+    Circle, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, PolygonIndex, Rect, Triangle,
 };
+pub use interpolation::{areal_interpolation, Attribute, Feature};
+pub use simplify::simplify_outlines;
 pub use to_geo::ToGeo;
 pub use to_h3::ToCells;
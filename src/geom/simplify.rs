@@ -0,0 +1,585 @@
+use ahash::{HashMap, HashMapExt, HashSet};
+use geo::{
+    line_intersection::{line_intersection, LineIntersection},
+    Contains, Coord, Line, LineString, MultiPolygon, Polygon,
+};
+
+/// Simplifies the outlines of a set of regions, while preserving their
+/// topology.
+///
+/// Rings are simplified using the Douglas-Peucker algorithm, with `tolerance`
+/// expressed in the unit of the coordinates (e.g. degrees for the output of
+/// [`ToGeo::to_geom(true)`](super::ToGeo::to_geom)).
+///
+/// The topology is preserved:
+/// - rings never intersect themselves nor each other.
+/// - holes stay inside their shells, and shells stay outside of each other.
+/// - borders shared between adjacent regions (or between polygons of the same
+///   region) are simplified the same way, thus stay identical.
+///
+/// As a consequence, some vertices may be kept even though they're within the
+/// tolerance.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{geom::{simplify_outlines, ToGeo}, CellIndex};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let region1 = index.grid_disk::<Vec<_>>(10).to_geom(true)?;
+/// let region2 = index
+///     .grid_ring_fast(11)
+///     .collect::<Option<Vec<_>>>()
+///     .unwrap_or_default()
+///     .to_geom(true)?;
+/// let simplified = simplify_outlines(&[region1, region2], 1e-3);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[must_use]
+pub fn simplify_outlines(
+    regions: &[MultiPolygon<f64>],
+    tolerance: f64,
+) -> Vec<MultiPolygon<f64>> {
+    let rings = regions
+        .iter()
+        .flat_map(MultiPolygon::iter)
+        .flat_map(|polygon| {
+            std::iter::once(polygon.exterior()).chain(polygon.interiors())
+        })
+        .map(|ring| {
+            // Skip the closing coordinate.
+            let mut coords = ring.0.clone();
+            coords.pop();
+            coords
+        })
+        .collect::<Vec<_>>();
+
+    let mut network = Network::new(&rings);
+    network.simplify(tolerance);
+
+    let mut rings = network.rings().into_iter();
+    let mut next_ring =
+        || rings.next().unwrap_or_else(|| LineString::new(Vec::new()));
+    regions
+        .iter()
+        .map(|region| {
+            region
+                .iter()
+                .map(|polygon| {
+                    let exterior = next_ring();
+                    let interiors = polygon
+                        .interiors()
+                        .iter()
+                        .map(|_| next_ring())
+                        .collect();
+                    Polygon::new(exterior, interiors)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// -----------------------------------------------------------------------------
+
+/// Hashable representation of a coordinate.
+type Key = (u64, u64);
+
+const fn key(coord: Coord<f64>) -> Key {
+    (coord.x.to_bits(), coord.y.to_bits())
+}
+
+/// A part of a ring, between two nodes (i.e. vertices shared by more than two
+/// edges, or anchors).
+struct Chain {
+    /// Original coordinates.
+    coords: Vec<Coord<f64>>,
+    /// Whether each coordinate is kept in the simplified chain.
+    is_kept: Vec<bool>,
+}
+
+/// A segment of a simplified chain.
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    /// Chain ID.
+    chain: usize,
+    /// Index of the start coordinate in the chain.
+    start: usize,
+    /// Index of the end coordinate in the chain.
+    end: usize,
+}
+
+/// The rings, as a network of chains.
+struct Network {
+    /// Chains, shared between the rings.
+    chains: Vec<Chain>,
+    /// Rings, as sequences of (possibly reversed) chains.
+    rings: Vec<Vec<(usize, bool)>>,
+}
+
+impl Network {
+    fn new(rings: &[Vec<Coord<f64>>]) -> Self {
+        let nodes = find_nodes(rings);
+        let mut chains = Vec::new();
+        let mut ids = HashMap::new();
+        let rings = rings
+            .iter()
+            .map(|ring| {
+                // Rotate the ring to start on a node.
+                let start = ring
+                    .iter()
+                    .position(|&coord| nodes.contains(&key(coord)))
+                    .expect("ring with nodes");
+                let mut coords = ring[start..].to_vec();
+                coords.extend_from_slice(&ring[..start]);
+                coords.push(coords[0]);
+
+                // Split it into chains.
+                let mut parts = Vec::new();
+                let mut part = vec![coords[0]];
+                for &coord in &coords[1..] {
+                    part.push(coord);
+                    if nodes.contains(&key(coord)) {
+                        let chain = std::mem::replace(&mut part, vec![coord]);
+                        parts.push(register(&mut chains, &mut ids, chain));
+                    }
+                }
+                parts
+            })
+            .collect();
+
+        Self { chains, rings }
+    }
+
+    /// Simplifies every chain, then refines them until the topology is
+    /// preserved.
+    fn simplify(&mut self, tolerance: f64) {
+        for chain in &mut self.chains {
+            douglas_peucker(chain, 0, chain.coords.len() - 1, tolerance);
+        }
+
+        loop {
+            let invalid = self.invalid_segments();
+            if invalid.is_empty() {
+                break;
+            }
+            for segment in invalid {
+                let chain = &mut self.chains[segment.chain];
+                let farthest =
+                    farthest(&chain.coords, segment.start, segment.end);
+                chain.is_kept[farthest.0] = true;
+            }
+        }
+    }
+
+    /// Returns the simplified rings, closed.
+    fn rings(&self) -> Vec<LineString<f64>> {
+        self.rings
+            .iter()
+            .map(|parts| {
+                let mut coords = Vec::new();
+                for &(id, is_reversed) in parts {
+                    let chain = &self.chains[id];
+                    let kept = chain
+                        .coords
+                        .iter()
+                        .zip(&chain.is_kept)
+                        .filter_map(|(&coord, &is_kept)| {
+                            is_kept.then_some(coord)
+                        })
+                        .collect::<Vec<_>>();
+                    // Skip the first coordinate, the last one of the previous
+                    // chain.
+                    if is_reversed {
+                        coords.extend(kept.into_iter().rev().skip(1));
+                    } else {
+                        coords.extend(kept.into_iter().skip(1));
+                    }
+                }
+                let first = *coords.last().expect("non-empty ring");
+                coords.insert(0, first);
+                LineString::new(coords)
+            })
+            .collect()
+    }
+
+    /// Returns the simplified segments that break the topology.
+    fn invalid_segments(&self) -> Vec<Segment> {
+        let segments = self.segments();
+        let grid = Grid::new(&self.chains, &segments);
+        let mut invalid = segments
+            .iter()
+            .filter(|segment| {
+                segment.end > segment.start + 1
+                    && !self.is_valid(segment, &segments, &grid)
+            })
+            .copied()
+            .collect::<Vec<_>>();
+
+        // Rings must keep at least three distinct vertices.
+        for parts in &self.rings {
+            let count = parts
+                .iter()
+                .map(|&(id, _)| {
+                    self.chains[id].is_kept.iter().filter(|&&kept| kept).count()
+                        - 1
+                })
+                .sum::<usize>();
+            if count < 3 {
+                invalid.extend(parts.iter().flat_map(|&(id, _)| {
+                    segments.iter().filter(move |segment| {
+                        segment.chain == id && segment.end > segment.start + 1
+                    })
+                }));
+            }
+        }
+
+        invalid
+    }
+
+    /// Returns the segments of the simplified chains.
+    fn segments(&self) -> Vec<Segment> {
+        self.chains
+            .iter()
+            .enumerate()
+            .flat_map(|(id, chain)| {
+                let kept = chain
+                    .is_kept
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, &is_kept)| is_kept.then_some(idx))
+                    .collect::<Vec<_>>();
+                kept.windows(2)
+                    .map(|pair| Segment {
+                        chain: id,
+                        start: pair[0],
+                        end: pair[1],
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Checks that a simplified segment doesn't cross any other segment and
+    /// doesn't sweep over any vertex.
+    fn is_valid(
+        &self,
+        segment: &Segment,
+        segments: &[Segment],
+        grid: &Grid,
+    ) -> bool {
+        let chain = &self.chains[segment.chain];
+        let line =
+            Line::new(chain.coords[segment.start], chain.coords[segment.end]);
+
+        let crosses = grid.query(&[line.start, line.end]).any(|idx| {
+            let other = &segments[idx];
+            if other.chain == segment.chain && other.start == segment.start {
+                return false;
+            }
+            let coords = &self.chains[other.chain].coords;
+            let other = Line::new(coords[other.start], coords[other.end]);
+            crosses(line, other)
+        });
+        if crosses {
+            return false;
+        }
+
+        // The area between the original chain and the shortcut must be empty.
+        let original = &chain.coords[segment.start..=segment.end];
+        let swept =
+            Polygon::new(LineString::new(original.to_vec()), Vec::new());
+        !grid.query(original).any(|idx| {
+            let other = &segments[idx];
+            let coords = &self.chains[other.chain].coords;
+            [coords[other.start], coords[other.end]]
+                .into_iter()
+                .any(|coord| {
+                    coord != line.start
+                        && coord != line.end
+                        && swept.contains(&coord)
+                })
+        })
+    }
+}
+
+/// Registers a chain, if not already known, and returns its ID and direction.
+fn register(
+    chains: &mut Vec<Chain>,
+    ids: &mut HashMap<(Key, Key), usize>,
+    coords: Vec<Coord<f64>>,
+) -> (usize, bool) {
+    let forward = (key(coords[0]), key(coords[1]));
+    let backward =
+        (key(coords[coords.len() - 1]), key(coords[coords.len() - 2]));
+    let is_reversed = backward < forward;
+    let canonical = if is_reversed { backward } else { forward };
+
+    let id = *ids.entry(canonical).or_insert_with(|| {
+        let mut coords = coords;
+        if is_reversed {
+            coords.reverse();
+        }
+        let mut is_kept = vec![false; coords.len()];
+        is_kept[0] = true;
+        is_kept[coords.len() - 1] = true;
+        chains.push(Chain { coords, is_kept });
+        chains.len() - 1
+    });
+
+    (id, is_reversed)
+}
+
+/// Finds the vertices where chains must be split.
+///
+/// Nodes are the vertices shared by more than two edges. Rings without (or
+/// with a single) node are anchored on deterministic vertices, so that the
+/// same anchors are picked for identical rings.
+fn find_nodes(rings: &[Vec<Coord<f64>>]) -> HashSet<Key> {
+    let mut neighbors = HashMap::<Key, HashSet<Key>>::new();
+    for ring in rings {
+        for (i, &coord) in ring.iter().enumerate() {
+            let next = ring[(i + 1) % ring.len()];
+            neighbors.entry(key(coord)).or_default().insert(key(next));
+            neighbors.entry(key(next)).or_default().insert(key(coord));
+        }
+    }
+    let mut nodes = neighbors
+        .into_iter()
+        .filter_map(|(vertex, neighbors)| {
+            (neighbors.len() != 2).then_some(vertex)
+        })
+        .collect::<HashSet<_>>();
+
+    for ring in rings {
+        let count = ring
+            .iter()
+            .filter(|&&coord| nodes.contains(&key(coord)))
+            .count();
+        if count == 0 {
+            let anchor = ring
+                .iter()
+                .copied()
+                .min_by_key(|&coord| key(coord))
+                .expect("non-empty ring");
+            nodes.insert(key(anchor));
+        }
+        if count <= 1 {
+            // Anchor on the farthest vertex from the node.
+            let node = ring
+                .iter()
+                .copied()
+                .find(|&coord| nodes.contains(&key(coord)))
+                .expect("anchored ring");
+            let anchor = ring
+                .iter()
+                .copied()
+                .max_by(|&a, &b| {
+                    distance2(node, a)
+                        .total_cmp(&distance2(node, b))
+                        .then_with(|| key(b).cmp(&key(a)))
+                })
+                .expect("non-empty ring");
+            nodes.insert(key(anchor));
+        }
+    }
+
+    nodes
+}
+
+/// Marks the coordinates to keep between `start` and `end`.
+fn douglas_peucker(
+    chain: &mut Chain,
+    start: usize,
+    end: usize,
+    tolerance: f64,
+) {
+    if end <= start + 1 {
+        return;
+    }
+    let (idx, distance) = farthest(&chain.coords, start, end);
+    if distance > tolerance {
+        chain.is_kept[idx] = true;
+        douglas_peucker(chain, start, idx, tolerance);
+        douglas_peucker(chain, idx, end, tolerance);
+    }
+}
+
+/// Returns the coordinate, strictly between `start` and `end`, that is the
+/// farthest from the segment joining them.
+fn farthest(coords: &[Coord<f64>], start: usize, end: usize) -> (usize, f64) {
+    let line = Line::new(coords[start], coords[end]);
+    (start + 1..end)
+        .map(|idx| (idx, segment_distance(line, coords[idx])))
+        .fold((start + 1, f64::NEG_INFINITY), |best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        })
+}
+
+/// Squared Euclidean distance between two coordinates.
+fn distance2(a: Coord<f64>, b: Coord<f64>) -> f64 {
+    (a.x - b.x).mul_add(a.x - b.x, (a.y - b.y).powi(2))
+}
+
+/// Euclidean distance between a coordinate and a segment.
+fn segment_distance(line: Line<f64>, coord: Coord<f64>) -> f64 {
+    let delta = line.delta();
+    let length2 = delta.x.mul_add(delta.x, delta.y.powi(2));
+    if length2 == 0. {
+        return distance2(line.start, coord).sqrt();
+    }
+    let t = (coord.x - line.start.x)
+        .mul_add(delta.x, (coord.y - line.start.y) * delta.y)
+        / length2;
+    let t = t.clamp(0., 1.);
+    let projection = Coord {
+        x: t.mul_add(delta.x, line.start.x),
+        y: t.mul_add(delta.y, line.start.y),
+    };
+    distance2(projection, coord).sqrt()
+}
+
+/// Returns true if two segments intersect anywhere else than on a shared
+/// endpoint.
+fn crosses(a: Line<f64>, b: Line<f64>) -> bool {
+    match line_intersection(a, b) {
+        None => false,
+        Some(LineIntersection::SinglePoint {
+            intersection,
+            is_proper,
+        }) => {
+            is_proper
+                || !((intersection == a.start || intersection == a.end)
+                    && (intersection == b.start || intersection == b.end))
+        }
+        Some(LineIntersection::Collinear { .. }) => true,
+    }
+}
+
+/// Returns the bounding box (lower left and upper right corners) of the
+/// coordinates.
+fn bounding_box(coords: &[Coord<f64>]) -> (Coord<f64>, Coord<f64>) {
+    coords.iter().fold(
+        (
+            Coord {
+                x: f64::INFINITY,
+                y: f64::INFINITY,
+            },
+            Coord {
+                x: f64::NEG_INFINITY,
+                y: f64::NEG_INFINITY,
+            },
+        ),
+        |(min, max), coord| {
+            (
+                Coord {
+                    x: min.x.min(coord.x),
+                    y: min.y.min(coord.y),
+                },
+                Coord {
+                    x: max.x.max(coord.x),
+                    y: max.y.max(coord.y),
+                },
+            )
+        },
+    )
+}
+
+// -----------------------------------------------------------------------------
+
+/// A uniform grid of buckets, to speed up the lookup of the segments.
+struct Grid {
+    /// Coordinates of the lower left corner.
+    origin: Coord<f64>,
+    /// Size of a bucket.
+    size: Coord<f64>,
+    /// Number of buckets per axis.
+    count: usize,
+    /// Segments (by index) intersecting each bucket.
+    buckets: Vec<Vec<usize>>,
+}
+
+impl Grid {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )] // Rough estimate on small values, truncation is fine.
+    fn new(chains: &[Chain], segments: &[Segment]) -> Self {
+        let (min, max) = chains
+            .iter()
+            .map(|chain| bounding_box(&chain.coords))
+            .reduce(|(min1, max1), (min2, max2)| {
+                (
+                    Coord {
+                        x: min1.x.min(min2.x),
+                        y: min1.y.min(min2.y),
+                    },
+                    Coord {
+                        x: max1.x.max(max2.x),
+                        y: max1.y.max(max2.y),
+                    },
+                )
+            })
+            .unwrap_or_default();
+        let count = ((segments.len() as f64).sqrt().ceil() as usize).max(1);
+        let size = Coord {
+            x: ((max.x - min.x) / count as f64).max(f64::EPSILON),
+            y: ((max.y - min.y) / count as f64).max(f64::EPSILON),
+        };
+
+        let mut grid = Self {
+            origin: min,
+            size,
+            count,
+            buckets: vec![Vec::new(); count * count],
+        };
+        for (idx, segment) in segments.iter().enumerate() {
+            let coords = &chains[segment.chain].coords;
+            for bucket in
+                grid.buckets_of(&[coords[segment.start], coords[segment.end]])
+            {
+                grid.buckets[bucket].push(idx);
+            }
+        }
+
+        grid
+    }
+
+    /// Returns the segments (by index) that may intersect the given
+    /// coordinates' bounding box.
+    ///
+    /// The same segment may be returned several times.
+    fn query<'a>(
+        &'a self,
+        coords: &[Coord<f64>],
+    ) -> impl Iterator<Item = usize> + 'a {
+        self.buckets_of(coords)
+            .flat_map(|bucket| self.buckets[bucket].iter().copied())
+    }
+
+    /// Returns the buckets overlapping the coordinates' bounding box.
+    fn buckets_of(&self, coords: &[Coord<f64>]) -> impl Iterator<Item = usize> {
+        let (min, max) = bounding_box(coords);
+        let (x1, y1) = self.bucket(min);
+        let (x2, y2) = self.bucket(max);
+        let count = self.count;
+        (x1..=x2).flat_map(move |x| (y1..=y2).map(move |y| y * count + x))
+    }
+
+    /// Returns the bucket coordinates of a point.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // Values are clamped.
+    fn bucket(&self, coord: Coord<f64>) -> (usize, usize) {
+        let index = |value: f64, origin: f64, size: f64| {
+            (((value - origin) / size).floor().max(0.) as usize)
+                .min(self.count - 1)
+        };
+        (
+            index(coord.x, self.origin.x, self.size.x),
+            index(coord.y, self.origin.y, self.size.y),
+        )
+    }
+}
@@ -1,8 +1,11 @@
-use super::VertexGraph;
+use super::{EdgeSet, VertexGraph};
 use crate::{
-    error::OutlinerError, CellIndex, DirectedEdgeIndex, LatLng, VertexIndex,
+    error::OutlinerError, routing::Reachability, CellIndex, DirectedEdgeIndex,
+    LatLng, VertexIndex,
+};
+use geo::{
+    Coord, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon,
 };
-use geo::{Coord, Line, LineString, MultiPolygon, Point, Polygon};
 use std::convert::Infallible;
 
 /// A trait to trace the outline of an H3 object.
@@ -67,10 +70,16 @@ where
 
     /// Creates a [`MultiPolygon`] describing the outline(s) of a set of cells.
     ///
+    /// Cells may have different resolutions (e.g. a compacted set): the
+    /// outline is the same as the one of the set uncompacted at the finest
+    /// resolution, but the fine cells are never materialized.
+    ///
     /// # Errors
     ///
-    /// All cell indexes must be unique and have the same resolution, otherwise
-    /// [`OutlinerError`] is returned.
+    /// All cell indexes must be unique and must not overlap (i.e. no cell can
+    /// be a descendant of another one), otherwise [`OutlinerError`] is
+    /// returned ([`OutlinerError::DuplicateInput`] and
+    /// [`OutlinerError::HeterogeneousResolution`] respectively).
     ///
     /// # Example
     ///
@@ -113,6 +122,81 @@ where
     }
 }
 
+impl ToGeo for Reachability {
+    type Error = OutlinerError;
+    type Output = MultiPolygon<f64>;
+
+    /// Creates a [`MultiPolygon`] describing the outline(s) of the reached
+    /// cells (a.k.a. the isochrone).
+    ///
+    /// # Errors
+    ///
+    /// Reached cells are unique and share the same resolution by construction,
+    /// thus this method cannot fail in practice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::ToGeo, routing::Router, CellIndex, DirectedEdgeIndex};
+    ///
+    /// let src = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let mut router = Router::new(|edge: DirectedEdgeIndex| edge.length_m())
+    ///     .max_cost(500.);
+    /// let isochrone = router.reachable([src])?.to_geom(true)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn to_geom(self, use_degrees: bool) -> Result<Self::Output, Self::Error> {
+        self.into_iter()
+            .map(|reached| reached.cell())
+            .to_geom(use_degrees)
+    }
+}
+
+impl ToGeo for EdgeSet {
+    type Error = Infallible;
+    type Output = MultiLineString<f64>;
+
+    /// Creates a [`MultiLineString`] where contiguous edges are chained into
+    /// single line strings, following their direction.
+    ///
+    /// # Errors
+    ///
+    /// This method cannot fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::{EdgeSet, ToGeo}, CellIndex};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let edges = EdgeSet::new(index.edges());
+    /// let outline = edges.to_geom(true).expect("cannot fail");
+    /// assert_eq!(outline.0.len(), 1);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    fn to_geom(self, use_degrees: bool) -> Result<Self::Output, Self::Error> {
+        let lines = self
+            .polylines()
+            .into_iter()
+            .map(|polyline| {
+                polyline
+                    .into_iter()
+                    .map(|vertex| {
+                        let mut coord: Coord<f64> = vertex.into();
+                        if !use_degrees {
+                            coord.x = coord.x.to_radians();
+                            coord.y = coord.y.to_radians();
+                        }
+                        coord
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(MultiLineString::new(lines))
+    }
+}
+
 impl ToGeo for CellIndex {
     type Error = Infallible;
     type Output = Polygon<f64>;
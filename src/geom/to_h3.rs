@@ -1,4 +1,4 @@
-use crate::{CellIndex, Resolution};
+use crate::{error::TooManyCells, CellIndex, Resolution};
 use std::boxed::Box;
 
 /// A trait to convert a geometry (or a collection of geometries) into a list of
@@ -24,7 +24,10 @@ pub trait ToCells {
     /// resolution.
     ///
     /// The output may contain duplicate indexes in case of overlapping input
-    /// geometries.
+    /// geometries (see [`Geometry::to_sorted_cells`][sorted] for a sorted
+    /// output without duplicates).
+    ///
+    /// [sorted]: super::Geometry::to_sorted_cells
     ///
     /// # Example
     ///
@@ -46,4 +49,39 @@ pub trait ToCells {
         &self,
         resolution: Resolution,
     ) -> Box<dyn Iterator<Item = CellIndex> + '_>;
+
+    /// Computes the coverage of the input, like [`Self::to_cells`], unless it
+    /// may exceed `limit` cells.
+    ///
+    /// The check relies on [`Self::max_cells_count`] and is done upfront:
+    /// nothing is allocated nor computed when the limit may be exceeded.
+    ///
+    /// # Errors
+    ///
+    /// [`TooManyCells`] if the coverage may contain more than `limit` cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{Resolution, geom::{Rect, ToCells}};
+    ///
+    /// let r = geo::Rect::new(
+    ///     geo::coord! { x: 0., y: 0. },
+    ///     geo::coord! { x: 90., y: 60. },
+    /// );
+    /// let rect = Rect::from_degrees(r)?;
+    /// assert!(rect.try_to_cells(Resolution::Fifteen, 1_000_000).is_err());
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    fn try_to_cells(
+        &self,
+        resolution: Resolution,
+        limit: usize,
+    ) -> Result<Box<dyn Iterator<Item = CellIndex> + '_>, TooManyCells> {
+        let count = self.max_cells_count(resolution);
+        if count > limit {
+            return Err(TooManyCells::new(count, limit));
+        }
+        Ok(self.to_cells(resolution))
+    }
 }
@@ -1,5 +1,6 @@
 use super::RingHierarchy;
 use crate::{error::OutlinerError, CellIndex, LatLng, Resolution};
+use ahash::{HashSet, HashSetExt};
 use geo::{LineString, MultiPolygon, Polygon};
 use std::collections::{btree_map::Entry, BTreeMap};
 
@@ -29,31 +30,42 @@ pub struct VertexGraph {
 
 impl VertexGraph {
     /// Initializes a new `VertexGraph` from the given set of cells.
+    ///
+    /// Cells may have different resolutions (e.g. a compacted set), as long
+    /// as they don't overlap: the outline is then the same as the one of the
+    /// set uncompacted at the finest resolution.
     pub fn from_cells(
         cells: impl IntoIterator<Item = CellIndex>,
     ) -> Result<Self, OutlinerError> {
-        let mut cells = cells.into_iter();
+        let cells = cells.into_iter().collect::<Vec<_>>();
         let mut graph = Self::default();
-        let mut item = cells.next();
-        let resolution =
-            item.map_or_else(|| Resolution::Zero, CellIndex::resolution);
+        let resolution = cells
+            .iter()
+            .map(|cell| cell.resolution())
+            .max()
+            .unwrap_or(Resolution::Zero);
+        let is_heterogeneous =
+            cells.iter().any(|cell| cell.resolution() != resolution);
+        if is_heterogeneous {
+            check_overlap(&cells)?;
+        }
 
-        while let Some(cell) = item {
-            if cell.resolution() != resolution {
-                return Err(OutlinerError::HeterogeneousResolution);
+        for cell in cells {
+            if cell.resolution() == resolution {
+                graph.insert_boundary(&cell.boundary())?;
+                continue;
             }
 
-            let boundary = cell.boundary();
-
-            // Iterate through every edge.
-            for i in 0..boundary.len() {
-                let from = boundary[i];
-                let to = boundary[(i + 1) % boundary.len()];
-
-                graph.insert(&Node { from, to })?;
+            // Only trace the edges of the fine cells that are on the outline
+            // of the coarse one.
+            for child in outer_descendants(cell, resolution) {
+                for edge in child.edges() {
+                    let neighbor = edge.destination().parent(cell.resolution());
+                    if neighbor != Some(cell) {
+                        graph.insert_path(&edge.boundary())?;
+                    }
+                }
             }
-
-            item = cells.next();
         }
 
         graph.prune();
@@ -61,6 +73,34 @@ impl VertexGraph {
         Ok(graph)
     }
 
+    /// Adds the edges of a closed boundary to the graph.
+    fn insert_boundary(
+        &mut self,
+        boundary: &[LatLng],
+    ) -> Result<(), OutlinerError> {
+        // Iterate through every edge.
+        for i in 0..boundary.len() {
+            let from = boundary[i];
+            let to = boundary[(i + 1) % boundary.len()];
+
+            self.insert(&Node { from, to })?;
+        }
+
+        Ok(())
+    }
+
+    /// Adds the edges of an open path to the graph.
+    fn insert_path(&mut self, path: &[LatLng]) -> Result<(), OutlinerError> {
+        for pair in path.windows(2) {
+            self.insert(&Node {
+                from: pair[0],
+                to: pair[1],
+            })?;
+        }
+
+        Ok(())
+    }
+
     /// Adds an edge to the graph.
     pub fn insert(&mut self, node: &Node) -> Result<(), OutlinerError> {
         // First lookup the reversed edge.
@@ -140,6 +180,60 @@ impl VertexGraph {
     }
 }
 
+/// Checks that no cell is a duplicate or a descendant of another one.
+fn check_overlap(cells: &[CellIndex]) -> Result<(), OutlinerError> {
+    let mut set = HashSet::with_capacity(cells.len());
+    for &cell in cells {
+        if !set.insert(cell) {
+            return Err(OutlinerError::DuplicateInput);
+        }
+    }
+    for &cell in cells {
+        let resolution = cell.resolution();
+        let has_ancestor = Resolution::range(Resolution::Zero, resolution)
+            .filter(|&res| res != resolution)
+            .filter_map(|res| cell.parent(res))
+            .any(|ancestor| set.contains(&ancestor));
+        if has_ancestor {
+            return Err(OutlinerError::HeterogeneousResolution);
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the descendants of the cell, at the given resolution, that are on
+/// the outline of the cell (i.e. that have a neighbor which isn't a
+/// descendant of the cell).
+///
+/// The tree of descendants is only explored near the outline: a child is
+/// skipped when none of the cells within distance 2 stands outside of the
+/// cell. The descendants of a child overflow its boundary, but stay within
+/// its direct neighbors, and so do the finest neighbors of those descendants
+/// (a fine cell being smaller than a ring of the child's resolution): if the
+/// ring 2 around the child is inside the cell, so are they. At the target
+/// resolution, the child is the fine cell itself and its direct neighbors
+/// are enough.
+fn outer_descendants(
+    cell: CellIndex,
+    resolution: Resolution,
+) -> Vec<CellIndex> {
+    let is_outside =
+        |index: CellIndex| index.parent(cell.resolution()) != Some(cell);
+    let mut cells = vec![cell];
+
+    for res in Resolution::range(cell.resolution(), resolution).skip(1) {
+        let k = if res == resolution { 1 } else { 2 };
+        cells = cells
+            .into_iter()
+            .flat_map(|cell| cell.children(res))
+            .filter(|child| child.grid_disk_safe(k).any(is_outside))
+            .collect();
+    }
+
+    cells
+}
+
 impl From<VertexGraph> for MultiPolygon<f64> {
     fn from(mut value: VertexGraph) -> Self {
         // No vertex, no shape.
@@ -1,8 +1,8 @@
 //! Hexagon grid algorithms.
 
 use crate::{
-    coord::FaceIJK, index::bits, BaseCell, CellIndex, Direction, Resolution,
-    CCW, CW,
+    coord::FaceIJK, index::bits, BaseCell, CellIndex, Direction, LatLng,
+    Resolution, CCW, CW,
 };
 
 // -----------------------------------------------------------------------------
@@ -200,6 +200,40 @@ pub fn direction_for_neighbor(
 
 // -----------------------------------------------------------------------------
 
+/// Returns the neighbors of the origin.
+///
+/// Unlike [`CellIndex::grid_disk`], this never fails and doesn't allocate.
+pub fn neighbors(origin: CellIndex) -> impl Iterator<Item = CellIndex> {
+    // Skips center since that would be the origin.
+    // Skips deleted `K` direction for pentagons.
+    let start = 1 + u8::from(origin.is_pentagon());
+
+    (start..=6).filter_map(move |value| {
+        // SAFETY: loop upper bound is 6 (a.k.a. `Direction::IJ`), which is a
+        // valid direction.
+        let direction = Direction::new_unchecked(value);
+        neighbor_rotations(origin, direction, 0).map(|(neighbor, _)| neighbor)
+    })
+}
+
+/// Returns a lower bound of the great circle distance (in meters) between the
+/// location and any point of the cell.
+///
+/// Cells are convex, thus every point of a cell is closer to its center than
+/// the farthest vertex.
+pub fn min_distance_m(cell: CellIndex, location: LatLng) -> f64 {
+    let center = LatLng::from(cell);
+    let radius = cell
+        .boundary()
+        .iter()
+        .map(|vertex| center.distance_m(*vertex))
+        .fold(0., f64::max);
+
+    (center.distance_m(location) - radius).max(0.)
+}
+
+// -----------------------------------------------------------------------------
+
 // Consts to save some typing below...
 const CENTER: Direction = Direction::Center;
 const K_AXE: Direction = Direction::K;
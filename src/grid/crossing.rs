@@ -0,0 +1,243 @@
+//! Walk along a great circle arc, from cell to cell.
+
+use crate::{coord::Vec3d, CellIndex, DirectedEdgeIndex, LatLng, Resolution};
+use ahash::HashSet;
+
+/// Angular tolerance (in radians, ~0.6mm on Earth) used to compare positions
+/// along the arc.
+const EPSILON: f64 = 1e-10;
+
+/// A crossing of a cell boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crossing {
+    /// The crossed edge, oriented along the arc.
+    pub edge: DirectedEdgeIndex,
+    /// Where the edge is crossed.
+    pub point: LatLng,
+}
+
+/// Computes the cell boundaries crossed by the great circle arc (the shortest
+/// one) between `start` and `end`, in order.
+///
+/// The walk starts from the cell containing `start` and always stops at the
+/// cell containing `end` (as given by [`LatLng::to_cell`]), every edge leads to
+/// a neighbor of the previous cell. Where the arc cannot be followed
+/// geometrically, the path is completed along the grid.
+///
+/// When the arc goes through a vertex, the cell containing the continuation of
+/// the arc is picked (the smallest one if the arc follows an edge). Between
+/// antipodal points, the arc follows the meridian of `start`.
+pub fn crossings(
+    start: LatLng,
+    end: LatLng,
+    resolution: Resolution,
+) -> Vec<Crossing> {
+    let arc = Arc::new(start, end);
+    let end_cell = end.to_cell(resolution);
+    let mut cell = start.to_cell(resolution);
+    let mut previous = None;
+    let mut visited = HashSet::default();
+    let mut position = 0.;
+    let mut crossings = Vec::new();
+
+    while cell != end_cell {
+        visited.insert(cell);
+        let Some((angle, edge)) = arc.exit(cell, previous, position) else {
+            break;
+        };
+        let destination = edge.destination();
+        if visited.contains(&destination) {
+            break;
+        }
+        crossings.push(Crossing {
+            edge,
+            point: arc.point(angle),
+        });
+        previous = Some(cell);
+        cell = destination;
+        position = angle;
+    }
+
+    // The walk may stop short of the end cell: the end point may be slightly
+    // off the geometrical boundary of its cell (rounding errors), or no exit
+    // can be found when the arc grazes a vertex. Complete the path along the
+    // grid, spreading the remaining crossings over the rest of the arc.
+    if cell != end_cell {
+        let path = complete(cell, end_cell);
+        #[allow(clippy::cast_precision_loss)] // Short path.
+        let step = (arc.length - position).max(0.) / (path.len() - 1) as f64;
+        crossings.extend(path.windows(2).enumerate().filter_map(
+            |(i, pair)| {
+                #[allow(clippy::cast_precision_loss)] // Short path.
+                let point = if i + 2 == path.len() {
+                    end
+                } else {
+                    arc.point(step.mul_add((i + 1) as f64, position))
+                };
+                pair[0].edge(pair[1]).map(|edge| Crossing { edge, point })
+            },
+        ));
+    }
+
+    crossings
+}
+
+/// Returns a path on the grid (inclusive) between two cells.
+///
+/// The shortest path is only searched for nearby cells (the usual case, where
+/// the walk stops a cell or two short of the end), as its cost explodes with
+/// the distance across pentagons. Far apart cells are joined by a line in the
+/// local `IJ` coordinate system when possible.
+fn complete(start: CellIndex, end: CellIndex) -> Vec<CellIndex> {
+    if start.grid_disk::<Vec<_>>(2).contains(&end) {
+        return super::shortest_path(start, end);
+    }
+    start
+        .grid_path_cells(end)
+        .ok()
+        .and_then(|path| path.collect::<Result<Vec<_>, _>>().ok())
+        .unwrap_or_else(|| super::shortest_path(start, end))
+}
+
+// -----------------------------------------------------------------------------
+
+/// A great circle arc.
+struct Arc {
+    /// Start of the arc.
+    start: Vec3d,
+    /// Unit normal of the arc's plane.
+    normal: Vec3d,
+    /// Length of the arc, in radians.
+    length: f64,
+}
+
+impl Arc {
+    fn new(start: LatLng, end: LatLng) -> Self {
+        let (start, end) = (Vec3d::from(start), Vec3d::from(end));
+        let normal = start.cross(&end);
+        let norm = normal.norm();
+        let length = norm.atan2(start.dot(&end));
+        let normal = if norm > f64::EPSILON {
+            scale(&normal, 1. / norm)
+        } else if start.dot(&end) < 0. {
+            // Antipodal points: every great circle through them is a shortest
+            // arc, follow the meridian of the start (or, from a pole, the
+            // prime meridian).
+            let normal = start.cross(&Vec3d::new(0., 0., 1.));
+            let normal = if normal.norm() > f64::EPSILON {
+                normal
+            } else {
+                start.cross(&Vec3d::new(1., 0., 0.))
+            };
+            scale(&normal, 1. / normal.norm())
+        } else {
+            // Same point, empty arc.
+            normal
+        };
+
+        Self {
+            start,
+            normal,
+            length,
+        }
+    }
+
+    /// Returns the point at the given angle from the start.
+    fn point(&self, angle: f64) -> LatLng {
+        let (sin, cos) = angle.sin_cos();
+        let ortho = self.normal.cross(&self.start);
+        let point = Vec3d::new(
+            self.start.x.mul_add(cos, ortho.x * sin),
+            self.start.y.mul_add(cos, ortho.y * sin),
+            self.start.z.mul_add(cos, ortho.z * sin),
+        );
+        LatLng::new_unchecked(
+            point.z.clamp(-1., 1.).asin(),
+            point.y.atan2(point.x),
+        )
+    }
+
+    /// Returns the angle, from the start, at which the arc crosses the arc
+    /// between `from` and `to` (if it does).
+    fn intersection(&self, from: LatLng, to: LatLng) -> Option<f64> {
+        let (from, to) = (Vec3d::from(from), Vec3d::from(to));
+        let normal = from.cross(&to);
+        let direction = self.normal.cross(&normal);
+        let norm = direction.norm();
+        // Parallel arcs: no single crossing point.
+        if norm <= f64::EPSILON {
+            return None;
+        }
+        let direction = scale(&direction, 1. / norm);
+        let edge_length = normal.norm().atan2(from.dot(&to));
+        let edge_normal = scale(&normal, 1. / normal.norm());
+
+        [direction, scale(&direction, -1.)]
+            .into_iter()
+            .find_map(|point| {
+                let on_edge = angle(&from, &point, &edge_normal);
+                let on_arc = angle(&self.start, &point, &self.normal);
+                ((-EPSILON..=edge_length + EPSILON).contains(&on_edge)
+                    && (-EPSILON..=self.length + EPSILON).contains(&on_arc))
+                .then_some(on_arc)
+            })
+    }
+
+    /// Finds where the arc leaves the cell, coming from `previous`, after the
+    /// given position.
+    fn exit(
+        &self,
+        cell: CellIndex,
+        previous: Option<CellIndex>,
+        position: f64,
+    ) -> Option<(f64, DirectedEdgeIndex)> {
+        let mut candidates = cell
+            .edges()
+            .filter(|edge| Some(edge.destination()) != previous)
+            .filter_map(|edge| {
+                let boundary = edge.boundary();
+                boundary
+                    .windows(2)
+                    .filter_map(|pair| self.intersection(pair[0], pair[1]))
+                    .filter(|&angle| angle >= position - EPSILON)
+                    .max_by(f64::total_cmp)
+                    .map(|angle| (angle, edge))
+            })
+            .collect::<Vec<_>>();
+        let exit = candidates
+            .iter()
+            .map(|&(angle, _)| angle)
+            .max_by(f64::total_cmp)?;
+
+        // Several edges are crossed at the same place when the arc goes
+        // through a vertex: follow the cell where the arc continues.
+        candidates.retain(|&(angle, _)| angle >= exit - EPSILON);
+        if candidates.len() > 1 {
+            let probe = self
+                .point(EPSILON.mul_add(10., exit).min(self.length))
+                .to_cell(cell.resolution());
+            candidates.sort_unstable_by_key(|&(_, edge)| edge);
+            if let Some(&candidate) = candidates
+                .iter()
+                .find(|&&(_, edge)| edge.destination() == probe)
+            {
+                return Some(candidate);
+            }
+        }
+
+        candidates.first().copied()
+    }
+}
+
+/// Returns the signed angle, around `normal`, from `from` to `to`.
+fn angle(from: &Vec3d, to: &Vec3d, normal: &Vec3d) -> f64 {
+    from.cross(to).dot(normal).atan2(from.dot(to))
+}
+
+fn scale(vector: &Vec3d, factor: f64) -> Vec3d {
+    Vec3d::new(vector.x * factor, vector.y * factor, vector.z * factor)
+}
+
+#[cfg(test)]
+#[path = "./crossing_tests.rs"]
+mod tests;
@@ -0,0 +1,63 @@
+use super::*;
+
+/// Checks that the crossings form a path from the start cell to the end cell.
+fn check(start: LatLng, end: LatLng, resolution: Resolution) {
+    let crossings = crossings(start, end, resolution);
+    let mut cell = start.to_cell(resolution);
+    for crossing in &crossings {
+        assert_eq!(crossing.edge.origin(), cell, "{start:?} -> {end:?}");
+        cell = crossing.edge.destination();
+    }
+
+    assert_eq!(cell, end.to_cell(resolution), "{start:?} -> {end:?}");
+}
+
+#[test]
+fn ends_in_end_cell() {
+    let origin = CellIndex::try_from(0x8a1fb46622dffff).expect("origin");
+    let start = LatLng::from(origin);
+
+    for cell in origin.grid_disk::<Vec<_>>(4) {
+        check(start, LatLng::from(cell), origin.resolution());
+    }
+}
+
+#[test]
+fn ends_in_end_cell_through_vertices() {
+    // Arcs between cell vertices run along the edges or through the vertices,
+    // where the walk cannot always find an exit.
+    for origin in [
+        CellIndex::try_from(0x8a1fb46622dffff).expect("hexagon"),
+        CellIndex::try_from(0x851c0003fffffff).expect("pentagon"),
+    ] {
+        let resolution = origin.resolution();
+        let vertices = origin
+            .grid_disk::<Vec<_>>(2)
+            .into_iter()
+            .flat_map(|cell| {
+                cell.boundary().iter().copied().collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        for &start in &vertices {
+            for &end in &vertices {
+                check(start, end, resolution);
+            }
+        }
+    }
+}
+
+#[test]
+fn ends_in_end_cell_when_walk_stops_early() {
+    // Vertex to vertex at res 15, where the geometrical walk falls short.
+    let start = LatLng::from_radians(1.095806315903715, 2.717350090740481)
+        .expect("start");
+    let end = LatLng::from_radians(1.0958063104186593, 2.7173506611286085)
+        .expect("end");
+    let result = crossings(start, end, Resolution::Fifteen);
+
+    assert_eq!(
+        result.last().map(|crossing| crossing.edge.destination()),
+        Some(end.to_cell(Resolution::Fifteen))
+    );
+    check(start, end, Resolution::Fifteen);
+}
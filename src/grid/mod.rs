@@ -1,5 +1,11 @@
 mod algo;
+mod crossing;
 mod iterator;
+mod path;
 
-pub use algo::{direction_for_neighbor, neighbor_rotations};
+pub use algo::{
+    direction_for_neighbor, min_distance_m, neighbor_rotations, neighbors,
+};
+pub use crossing::crossings;
 pub use iterator::{DiskDistancesSafe, DiskDistancesUnsafe, RingUnsafe};
+pub use path::{distance, shortest_path};
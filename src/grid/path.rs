@@ -0,0 +1,164 @@
+//! Shortest path between two cells, robust to pentagons and face crossings.
+
+use super::neighbors;
+use crate::{CellIndex, LatLng, Resolution};
+use ahash::{HashMap, HashMapExt};
+
+/// Upper bound of the great circle distance between the centers of two
+/// neighboring cells, relative to the average edge length of the resolution.
+///
+/// Empirically, the ratio peaks at ~2.2 (reached at the finest resolutions).
+const MAX_NEIGHBOR_DISTANCE_RATIO: f64 = 2.5;
+
+/// Computes a shortest path (inclusive) between two cells at the same
+/// resolution.
+///
+/// The local `IJ` coordinate system is used when the area covered by the path
+/// is known to be free of pentagon distortion, otherwise a bidirectional BFS
+/// is used.
+pub fn shortest_path(src: CellIndex, dst: CellIndex) -> Vec<CellIndex> {
+    debug_assert_eq!(src.resolution(), dst.resolution());
+
+    local_ij_path(src, dst).unwrap_or_else(|| bfs_path(src, dst))
+}
+
+/// Computes the grid distance between two cells at the same resolution.
+///
+/// See [`shortest_path`] for details.
+pub fn distance(src: CellIndex, dst: CellIndex) -> i32 {
+    debug_assert_eq!(src.resolution(), dst.resolution());
+
+    match src.grid_distance(dst) {
+        Ok(distance) if is_pentagon_free(src, distance.unsigned_abs()) => {
+            distance
+        }
+        _ => {
+            let path = bfs_path(src, dst);
+            i32::try_from(path.len() - 1).expect("grid distance overflow")
+        }
+    }
+}
+
+/// Computes the path using the local `IJ` coordinate system.
+///
+/// Returns `None` if the local `IJ` coordinate system cannot be used or if it
+/// may not give the shortest path.
+fn local_ij_path(src: CellIndex, dst: CellIndex) -> Option<Vec<CellIndex>> {
+    let distance = src.grid_distance(dst).ok()?;
+    if !is_pentagon_free(src, distance.unsigned_abs()) {
+        return None;
+    }
+    src.grid_path_cells(dst)
+        .ok()?
+        .collect::<Result<Vec<_>, _>>()
+        .ok()
+}
+
+/// Returns true if there is no pentagon within `k` steps of the origin.
+///
+/// Inside such an area, the grid is a regular hexagonal tiling and thus the
+/// local `IJ` coordinates yield exact grid distances.
+fn is_pentagon_free(origin: CellIndex, k: u32) -> bool {
+    let resolution = origin.resolution();
+    let radius = f64::from(k.saturating_add(1))
+        * MAX_NEIGHBOR_DISTANCE_RATIO
+        * resolution.edge_length_rads();
+    let center = LatLng::from(origin);
+
+    Resolution::pentagons(resolution)
+        .all(|pentagon| center.distance_rads(pentagon.into()) > radius)
+}
+
+/// Computes the path using a bidirectional breadth-first search.
+fn bfs_path(src: CellIndex, dst: CellIndex) -> Vec<CellIndex> {
+    if src == dst {
+        return vec![src];
+    }
+
+    // Each search tree map a visited cell to its parent.
+    let mut forward = Search::new(src);
+    let mut backward = Search::new(dst);
+
+    // Always expand the smallest frontier, to keep the explored area minimal.
+    let junction = loop {
+        let (current, other) =
+            if forward.frontier.len() <= backward.frontier.len() {
+                (&mut forward, &backward)
+            } else {
+                (&mut backward, &forward)
+            };
+        if let Some(junction) = current.expand(other) {
+            break junction;
+        }
+    };
+
+    // Since the visited sets were disjoint until now, the first junction found
+    // is on a shortest path.
+    let mut path = forward.path_to(junction);
+    path.reverse();
+    path.extend(backward.path_to(junction).into_iter().skip(1));
+    path
+}
+
+// -----------------------------------------------------------------------------
+
+/// One side of a bidirectional breadth-first search.
+struct Search {
+    /// Visited cells, with their parent.
+    parents: HashMap<CellIndex, CellIndex>,
+    /// Cells discovered at the last expansion.
+    frontier: Vec<CellIndex>,
+}
+
+impl Search {
+    fn new(origin: CellIndex) -> Self {
+        let mut parents = HashMap::new();
+        parents.insert(origin, origin);
+
+        Self {
+            parents,
+            frontier: vec![origin],
+        }
+    }
+
+    /// Expands the frontier by one ring.
+    ///
+    /// Returns the first cell that has already been visited by the other
+    /// search, if any.
+    fn expand(&mut self, other: &Self) -> Option<CellIndex> {
+        let mut next = Vec::with_capacity(self.frontier.len() + 6);
+
+        for &cell in &self.frontier {
+            for neighbor in neighbors(cell) {
+                if self.parents.contains_key(&neighbor) {
+                    continue;
+                }
+                self.parents.insert(neighbor, cell);
+                if other.parents.contains_key(&neighbor) {
+                    return Some(neighbor);
+                }
+                next.push(neighbor);
+            }
+        }
+        self.frontier = next;
+
+        None
+    }
+
+    /// Returns the path from the given cell back to the search origin.
+    fn path_to(&self, mut cell: CellIndex) -> Vec<CellIndex> {
+        let mut path = vec![cell];
+        loop {
+            let parent = self.parents[&cell];
+            if parent == cell {
+                return path;
+            }
+            path.push(parent);
+            cell = parent;
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./path_tests.rs"]
+mod tests;
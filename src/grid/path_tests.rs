@@ -0,0 +1,49 @@
+use super::*;
+
+#[test]
+fn bfs_matches_local_ij() {
+    let origin = CellIndex::try_from(0x8a1fb46622dffff).expect("origin");
+
+    for (cell, k) in origin.grid_disk_distances::<Vec<_>>(5) {
+        let path = bfs_path(origin, cell);
+
+        assert_eq!(path.len(), usize::try_from(k).expect("k") + 1, "{cell}");
+        assert_eq!(path.first(), Some(&origin));
+        assert_eq!(path.last(), Some(&cell));
+    }
+}
+
+#[test]
+fn around_pentagon() {
+    let pentagon = Resolution::Five.pentagons().next().expect("pentagon");
+    let ring = pentagon.grid_disk_safe(3).collect::<Vec<_>>();
+
+    for &src in &ring {
+        for &dst in &ring {
+            let path = shortest_path(src, dst);
+
+            assert_eq!(path.first(), Some(&src));
+            assert_eq!(path.last(), Some(&dst));
+            assert!(path.windows(2).all(|pair| pair[0]
+                .is_neighbor_with(pair[1])
+                .expect("same resolution")));
+            assert_eq!(
+                usize::try_from(distance(src, dst)).expect("distance") + 1,
+                path.len()
+            );
+        }
+    }
+}
+
+#[test]
+fn neighbors_of_pentagon() {
+    let pentagon = Resolution::Five.pentagons().next().expect("pentagon");
+    let mut cells = neighbors(pentagon).collect::<Vec<_>>();
+    cells.sort_unstable();
+
+    let mut expected = pentagon.grid_disk::<Vec<_>>(1);
+    expected.retain(|&cell| cell != pentagon);
+    expected.sort_unstable();
+
+    assert_eq!(cells, expected);
+}
@@ -1239,6 +1239,82 @@ impl CellIndex {
         GridPathCells::new(self, to)
     }
 
+    /// Safe but slow version of [`Self::grid_distance`].
+    ///
+    /// Unlike [`Self::grid_distance`], this function never fails on far apart
+    /// indexes nor across pentagons: it falls back on a graph traversal when
+    /// the local `IJ` coordinate system cannot be used.
+    ///
+    /// Note that the fallback explores an area quadratic in the distance, so
+    /// it can be very slow for far apart indexes at fine resolutions.
+    ///
+    /// # Errors
+    ///
+    /// [`ResolutionMismatch`] if the source and destination indexes don't have
+    /// the same resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    ///
+    /// let src = CellIndex::try_from(0x81083ffffffffff)?;
+    /// let dst = CellIndex::try_from(0x810a3ffffffffff)?;
+    /// assert!(src.grid_distance(dst).is_err());
+    /// assert_eq!(src.grid_distance_safe(dst)?, 5);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn grid_distance_safe(
+        self,
+        to: Self,
+    ) -> Result<i32, ResolutionMismatch> {
+        if self.resolution() != to.resolution() {
+            return Err(ResolutionMismatch);
+        }
+
+        Ok(grid::distance(self, to))
+    }
+
+    /// Safe but slow version of [`Self::grid_path_cells`].
+    ///
+    /// Unlike [`Self::grid_path_cells`], this function never fails on far
+    /// apart indexes nor across pentagons: it falls back on a graph traversal
+    /// when the local `IJ` coordinate system cannot be used.
+    ///
+    /// The path is a shortest one: its length is always
+    /// `start.grid_distance_safe(end) + 1` and every index in the path is a
+    /// neighbor of the preceding index.
+    ///
+    /// Note that the fallback explores an area quadratic in the distance, so
+    /// it can be very slow for far apart indexes at fine resolutions.
+    ///
+    /// # Errors
+    ///
+    /// [`ResolutionMismatch`] if the source and destination indexes don't have
+    /// the same resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    ///
+    /// let src = CellIndex::try_from(0x81083ffffffffff)?;
+    /// let dst = CellIndex::try_from(0x810a3ffffffffff)?;
+    /// let cells = src.grid_path_cells_safe(dst)?.collect::<Vec<_>>();
+    /// assert_eq!(cells.len(), 6);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn grid_path_cells_safe(
+        self,
+        to: Self,
+    ) -> Result<impl Iterator<Item = Self>, ResolutionMismatch> {
+        if self.resolution() != to.resolution() {
+            return Err(ResolutionMismatch);
+        }
+
+        Ok(grid::shortest_path(self, to).into_iter())
+    }
+
     /// Returns whether or not the provided cell index is a neighbor of the
     /// current one.
     ///
@@ -14,4 +14,4 @@ pub use mode::IndexMode;
 pub use vertex::{Vertex, VertexIndex};
 
 use iterator::{Children, Compact, GridPathCells};
-use triangle::Triangle;
+pub use triangle::Triangle;
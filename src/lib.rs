@@ -235,6 +235,7 @@ use konst::{primitive::parse_u8 as as_u8, result::unwrap_ctx as unwrap};
 
 mod base_cell;
 mod boundary;
+pub mod cell_set;
 mod coord;
 mod direction;
 pub mod error;
@@ -243,7 +244,10 @@ mod face;
 pub mod geom;
 mod grid;
 mod index;
+pub mod point_index;
 mod resolution;
+pub mod routing;
+pub mod trajectory;
 
 pub use base_cell::BaseCell;
 pub use boundary::Boundary;
@@ -0,0 +1,288 @@
+//! Spatial index of points, bucketed by cell.
+//!
+//! Points are stored in the cell containing them, at a fixed resolution, and
+//! queries explore the cells around the query location from the nearest to the
+//! farthest, using a lower bound of the great circle distance to every cell to
+//! stop as soon as the result can't change anymore (pentagons included).
+//!
+//! The resolution should be picked so that cells hold a few points each: too
+//! coarse and queries compare many points, too fine and they visit many empty
+//! cells.
+//!
+//! ```
+//! use h3o::{point_index::PointIndex, LatLng, Resolution};
+//!
+//! let mut index = PointIndex::new(Resolution::Nine);
+//! index.insert(LatLng::new(48.864716, 2.349014)?, "Centre Pompidou");
+//! index.insert(LatLng::new(48.858093, 2.294694)?, "Eiffel Tower");
+//!
+//! let location = LatLng::new(48.8606, 2.3376)?;
+//! let nearest = index.nearest(location, 1);
+//! assert_eq!(nearest[0].1, &"Centre Pompidou");
+//! # Ok::<(), h3o::error::InvalidLatLng>(())
+//! ```
+
+use crate::{grid, CellIndex, LatLng, Resolution};
+use ahash::{HashMap, HashSet};
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+};
+
+/// An index of points, with payloads, bucketed by cell.
+#[derive(Debug, Clone)]
+pub struct PointIndex<T> {
+    /// Resolution of the buckets.
+    resolution: Resolution,
+    /// Points, by cell.
+    cells: HashMap<CellIndex, Vec<(LatLng, T)>>,
+    /// Number of points.
+    len: usize,
+}
+
+impl<T> PointIndex<T> {
+    /// Initializes a new, empty, index using buckets at the given resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{point_index::PointIndex, Resolution};
+    ///
+    /// let index = PointIndex::<u32>::new(Resolution::Nine);
+    /// ```
+    #[must_use]
+    pub fn new(resolution: Resolution) -> Self {
+        Self {
+            resolution,
+            cells: HashMap::default(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of points in the index.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the index contains no point.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts a point, with its payload, into the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{point_index::PointIndex, LatLng, Resolution};
+    ///
+    /// let mut index = PointIndex::new(Resolution::Nine);
+    /// index.insert(LatLng::new(48.864716, 2.349014)?, 42);
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    pub fn insert(&mut self, point: LatLng, value: T) {
+        self.cells
+            .entry(point.to_cell(self.resolution))
+            .or_default()
+            .push((point, value));
+        self.len += 1;
+    }
+
+    /// Removes a point, with the given payload, from the index.
+    ///
+    /// Returns the removed payload, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{point_index::PointIndex, LatLng, Resolution};
+    ///
+    /// let point = LatLng::new(48.864716, 2.349014)?;
+    /// let mut index = PointIndex::new(Resolution::Nine);
+    /// index.insert(point, 42);
+    /// assert_eq!(index.remove(point, &42), Some(42));
+    /// assert!(index.is_empty());
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    pub fn remove(&mut self, point: LatLng, value: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        let cell = point.to_cell(self.resolution);
+        let points = self.cells.get_mut(&cell)?;
+        let position = points
+            .iter()
+            .position(|entry| entry.0 == point && entry.1 == *value)?;
+        let (_, value) = points.swap_remove(position);
+        if points.is_empty() {
+            self.cells.remove(&cell);
+        }
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    /// Returns the points within `radius` meters (inclusive) of the location.
+    ///
+    /// Points are returned with their payload and their distance to the
+    /// location (in meters), sorted by increasing distance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{point_index::PointIndex, LatLng, Resolution};
+    ///
+    /// let mut index = PointIndex::new(Resolution::Nine);
+    /// index.insert(LatLng::new(48.864716, 2.349014)?, "Centre Pompidou");
+    /// index.insert(LatLng::new(48.858093, 2.294694)?, "Eiffel Tower");
+    ///
+    /// let location = LatLng::new(48.8606, 2.3376)?;
+    /// let points = index.within(location, 1000.);
+    /// assert_eq!(points.len(), 1);
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub fn within(
+        &self,
+        location: LatLng,
+        radius: f64,
+    ) -> Vec<(LatLng, &T, f64)> {
+        let mut result = Vec::new();
+        if self.is_empty() {
+            return result;
+        }
+
+        // Every cell with a point within the radius is connected to the
+        // origin by cells that are within the radius as well (those crossed
+        // by the arc leading to the point).
+        let origin = location.to_cell(self.resolution);
+        let mut visited = HashSet::default();
+        let mut queue = vec![origin];
+        visited.insert(origin);
+        while let Some(cell) = queue.pop() {
+            result.extend(
+                self.points(cell, location)
+                    .filter(|&(_, _, distance)| distance <= radius),
+            );
+            for neighbor in grid::neighbors(cell) {
+                if visited.insert(neighbor)
+                    && grid::min_distance_m(neighbor, location) <= radius
+                {
+                    queue.push(neighbor);
+                }
+            }
+        }
+
+        result.sort_by(|a, b| a.2.total_cmp(&b.2));
+        result
+    }
+
+    /// Returns the `k` points nearest to the location.
+    ///
+    /// Points are returned with their payload and their distance to the
+    /// location (in meters), sorted by increasing distance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{point_index::PointIndex, LatLng, Resolution};
+    ///
+    /// let mut index = PointIndex::new(Resolution::Nine);
+    /// index.insert(LatLng::new(48.864716, 2.349014)?, "Centre Pompidou");
+    /// index.insert(LatLng::new(48.858093, 2.294694)?, "Eiffel Tower");
+    ///
+    /// let location = LatLng::new(48.8606, 2.3376)?;
+    /// let points = index.nearest(location, 2);
+    /// assert_eq!(points[1].1, &"Eiffel Tower");
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub fn nearest(
+        &self,
+        location: LatLng,
+        k: usize,
+    ) -> Vec<(LatLng, &T, f64)> {
+        let k = k.min(self.len);
+        let mut result = Vec::with_capacity(k);
+        if k == 0 {
+            return result;
+        }
+
+        // Best-first exploration: cells are visited by increasing lower bound
+        // of their distance to the location, which is also the smallest
+        // distance of the points that are yet to be seen.
+        let origin = location.to_cell(self.resolution);
+        let mut visited = HashSet::default();
+        let mut queue = BinaryHeap::new();
+        let mut seen = 0;
+        visited.insert(origin);
+        queue.push(Reverse(Candidate {
+            distance: 0.,
+            cell: origin,
+        }));
+        while let Some(Reverse(Candidate { distance, cell })) = queue.pop() {
+            if result.len() == k && result[k - 1].2 <= distance {
+                break;
+            }
+            if let Some(points) = self.cells.get(&cell) {
+                seen += points.len();
+                result.extend(self.points(cell, location));
+                result.sort_by(|a, b| a.2.total_cmp(&b.2));
+                result.truncate(k);
+            }
+            if result.len() == k && seen == self.len {
+                break;
+            }
+            for neighbor in grid::neighbors(cell) {
+                if visited.insert(neighbor) {
+                    queue.push(Reverse(Candidate {
+                        distance: grid::min_distance_m(neighbor, location),
+                        cell: neighbor,
+                    }));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Returns the points of the cell, with their distance to the location.
+    fn points(
+        &self,
+        cell: CellIndex,
+        location: LatLng,
+    ) -> impl Iterator<Item = (LatLng, &T, f64)> {
+        self.cells.get(&cell).into_iter().flatten().map(
+            move |&(point, ref value)| {
+                (point, value, point.distance_m(location))
+            },
+        )
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// A cell to explore, with the lower bound of its distance to the location.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candidate {
+    distance: f64,
+    cell: CellIndex,
+}
+
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then_with(|| self.cell.cmp(&other.cell))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,21 @@
+//! Weighted traversal of the cell grid.
+//!
+//! Movement costs are provided by the caller, per directed edge, which allows
+//! to model any kind of anisotropic cost (terrain, sea, restricted zones, …).
+//!
+//! ```
+//! use h3o::{routing::Router, CellIndex};
+//!
+//! let src = CellIndex::try_from(0x8a1fb46622dffff)?;
+//! let dst = CellIndex::try_from(0x8a1fb4644937fff)?;
+//! let mut router = Router::new(|edge: h3o::DirectedEdgeIndex| edge.length_m());
+//! let route = router.dijkstra(src, dst)?.expect("route");
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod reachability;
+mod router;
+mod search;
+
+pub use reachability::{Reachability, Reached};
+pub use router::{Route, Router};
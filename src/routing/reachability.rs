@@ -0,0 +1,121 @@
+use crate::CellIndex;
+
+/// A cell reached during a multi-source search.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reached {
+    /// Reached cell.
+    cell: CellIndex,
+    /// Cheapest cost to reach the cell.
+    cost: f64,
+    /// Source from which the cell is the cheapest to reach.
+    source: CellIndex,
+}
+
+impl Reached {
+    pub(super) const fn new(
+        cell: CellIndex,
+        cost: f64,
+        source: CellIndex,
+    ) -> Self {
+        Self { cell, cost, source }
+    }
+
+    /// Returns the reached cell.
+    #[must_use]
+    pub const fn cell(&self) -> CellIndex {
+        self.cell
+    }
+
+    /// Returns the cheapest cost to reach the cell.
+    #[must_use]
+    pub const fn cost(&self) -> f64 {
+        self.cost
+    }
+
+    /// Returns the source from which the cell is the cheapest to reach.
+    #[must_use]
+    pub const fn source(&self) -> CellIndex {
+        self.source
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// The set of cells reachable from one or more sources.
+///
+/// Cells are sorted by increasing cost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reachability {
+    /// Reached cells, sorted by cost.
+    cells: Vec<Reached>,
+    /// Whether the search has been stopped by the cell budget.
+    is_truncated: bool,
+}
+
+impl Reachability {
+    pub(super) const fn new(cells: Vec<Reached>, is_truncated: bool) -> Self {
+        Self {
+            cells,
+            is_truncated,
+        }
+    }
+
+    /// Returns an iterator over the reached cells, by increasing cost.
+    pub fn iter(&self) -> impl Iterator<Item = &Reached> {
+        self.cells.iter()
+    }
+
+    /// Returns the number of reached cells.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns true if no cell has been reached.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns true if the search has been stopped by the cell budget, i.e.
+    /// some reachable cells are missing.
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.is_truncated
+    }
+
+    /// Returns the cells reachable with a cost lower or equal to `cost`.
+    ///
+    /// Useful to extract several isochrones out of a single search.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{routing::Router, CellIndex, DirectedEdgeIndex};
+    ///
+    /// let src = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let mut router = Router::new(|edge: DirectedEdgeIndex| edge.length_m())
+    ///     .max_cost(1000.);
+    /// let reachability = router.reachable([src])?;
+    /// let cells = reachability.cells_within(500.).collect::<Vec<_>>();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn cells_within(
+        &self,
+        cost: f64,
+    ) -> impl Iterator<Item = CellIndex> + '_ {
+        self.cells
+            .iter()
+            .take_while(move |reached| reached.cost <= cost)
+            .map(|reached| reached.cell)
+    }
+}
+
+impl IntoIterator for Reachability {
+    type Item = Reached;
+    type IntoIter = std::vec::IntoIter<Reached>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
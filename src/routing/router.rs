@@ -0,0 +1,358 @@
+use super::{search::Search, Reachability, Reached};
+use crate::{error::ResolutionMismatch, CellIndex, DirectedEdgeIndex, LatLng};
+use ahash::HashSet;
+
+/// Shortest path finder over the cell grid.
+///
+/// The cost of moving from a cell to one of its neighbors is given by a user
+/// provided callback, invoked on the corresponding directed edge.
+/// Edges with a non-finite cost (infinity or NaN) are considered impassable.
+///
+/// Costs must be non-negative.
+pub struct Router<F> {
+    /// Cost function, per directed edge.
+    cost: F,
+    /// Cells that cannot be traversed.
+    blocked: HashSet<CellIndex>,
+    /// Maximum grid distance from the source(s), if any.
+    max_radius: Option<u32>,
+    /// Maximum cost from the source(s), if any.
+    max_cost: Option<f64>,
+    /// Maximum number of cells to visit, if any.
+    max_cells: Option<usize>,
+}
+
+impl<F> Router<F>
+where
+    F: FnMut(DirectedEdgeIndex) -> f64,
+{
+    /// Initializes a new router using the given cost function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{routing::Router, DirectedEdgeIndex};
+    ///
+    /// let router = Router::new(|edge: DirectedEdgeIndex| edge.length_rads());
+    /// ```
+    pub fn new(cost: F) -> Self {
+        Self {
+            cost,
+            blocked: HashSet::default(),
+            max_radius: None,
+            max_cost: None,
+            max_cells: None,
+        }
+    }
+
+    /// Marks the given cells as impassable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{routing::Router, CellIndex, DirectedEdgeIndex};
+    ///
+    /// let wall = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let router = Router::new(|edge: DirectedEdgeIndex| edge.length_rads())
+    ///     .blocked([wall]);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn blocked(
+        mut self,
+        cells: impl IntoIterator<Item = CellIndex>,
+    ) -> Self {
+        self.blocked.extend(cells);
+        self
+    }
+
+    /// Restricts the search to the cells within grid distance `k` of the
+    /// source(s).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{routing::Router, DirectedEdgeIndex};
+    ///
+    /// let router = Router::new(|edge: DirectedEdgeIndex| edge.length_rads())
+    ///     .max_radius(10);
+    /// ```
+    #[must_use]
+    pub const fn max_radius(mut self, k: u32) -> Self {
+        self.max_radius = Some(k);
+        self
+    }
+
+    /// Restricts the search to the cells reachable from the source(s) with a
+    /// cost lower or equal to `cost`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{routing::Router, DirectedEdgeIndex};
+    ///
+    /// let router = Router::new(|edge: DirectedEdgeIndex| edge.length_m())
+    ///     .max_cost(1000.);
+    /// ```
+    #[must_use]
+    pub const fn max_cost(mut self, cost: f64) -> Self {
+        self.max_cost = Some(cost);
+        self
+    }
+
+    /// Stops the search after `count` cells have been visited.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{routing::Router, DirectedEdgeIndex};
+    ///
+    /// let router = Router::new(|edge: DirectedEdgeIndex| edge.length_m())
+    ///     .max_cells(10_000);
+    /// ```
+    #[must_use]
+    pub const fn max_cells(mut self, count: usize) -> Self {
+        self.max_cells = Some(count);
+        self
+    }
+
+    /// Computes the cheapest route between two cells, using Dijkstra's
+    /// algorithm.
+    ///
+    /// Returns `None` if the destination cannot be reached (within the search
+    /// limits).
+    ///
+    /// # Errors
+    ///
+    /// [`ResolutionMismatch`] if the source and destination indexes don't have
+    /// the same resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{routing::Router, CellIndex, DirectedEdgeIndex};
+    ///
+    /// let src = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let dst = CellIndex::try_from(0x8a1fb4644937fff)?;
+    /// let mut router = Router::new(|edge: DirectedEdgeIndex| edge.length_m());
+    /// let route = router.dijkstra(src, dst)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn dijkstra(
+        &mut self,
+        src: CellIndex,
+        dst: CellIndex,
+    ) -> Result<Option<Route>, ResolutionMismatch> {
+        self.route(src, dst, |_| 0.)
+    }
+
+    /// Computes the cheapest route between two cells, using the A* algorithm.
+    ///
+    /// The search is guided by the great circle distance to the destination:
+    /// `min_cost_per_rad` must be a lower bound of the cost of any edge divided
+    /// by the distance, in radians, between the centers of its cells.
+    /// Otherwise the returned route may not be the cheapest one.
+    ///
+    /// Returns `None` if the destination cannot be reached (within the search
+    /// limits).
+    ///
+    /// # Errors
+    ///
+    /// [`ResolutionMismatch`] if the source and destination indexes don't have
+    /// the same resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{routing::Router, CellIndex, DirectedEdgeIndex, LatLng};
+    ///
+    /// let src = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let dst = CellIndex::try_from(0x8a1fb4644937fff)?;
+    /// let mut router = Router::new(|edge: DirectedEdgeIndex| {
+    ///     let (src, dst) = edge.cells();
+    ///     LatLng::from(src).distance_rads(dst.into())
+    /// });
+    /// let route = router.astar(src, dst, 1.)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn astar(
+        &mut self,
+        src: CellIndex,
+        dst: CellIndex,
+        min_cost_per_rad: f64,
+    ) -> Result<Option<Route>, ResolutionMismatch> {
+        let target = LatLng::from(dst);
+        self.route(src, dst, |cell| {
+            LatLng::from(cell).distance_rads(target) * min_cost_per_rad
+        })
+    }
+
+    /// Computes the cheapest cost to reach every cell reachable from the given
+    /// sources (i.e. a multi-source Dijkstra's algorithm).
+    ///
+    /// Each reached cell is attributed to the source it's the cheapest to
+    /// reach from (ties are broken deterministically).
+    ///
+    /// You probably want to set a cost limit (see [`Self::max_cost`]) and/or a
+    /// cell budget (see [`Self::max_cells`]): without limits, the whole grid
+    /// will be explored.
+    ///
+    /// # Errors
+    ///
+    /// [`ResolutionMismatch`] if the sources don't have the same resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{routing::Router, CellIndex, DirectedEdgeIndex};
+    ///
+    /// let src = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let mut router = Router::new(|edge: DirectedEdgeIndex| edge.length_m())
+    ///     .max_cost(500.);
+    /// let reachability = router.reachable([src])?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn reachable(
+        &mut self,
+        sources: impl IntoIterator<Item = CellIndex>,
+    ) -> Result<Reachability, ResolutionMismatch> {
+        let mut sources = sources.into_iter().collect::<Vec<_>>();
+        let mut resolutions = sources.iter().map(|cell| cell.resolution());
+        if let Some(resolution) = resolutions.next() {
+            if resolutions.any(|res| res != resolution) {
+                return Err(ResolutionMismatch);
+            }
+        }
+        sources.retain(|cell| !self.blocked.contains(cell));
+
+        let area = self.area(&sources);
+        let max_cells = self.max_cells.unwrap_or(usize::MAX);
+        let mut search = Search::new(sources, |_| 0., self.cost_limit());
+        let mut cells = Vec::new();
+        let mut is_truncated = false;
+
+        while let Some((cell, node)) =
+            search.next(|edge| self.edge_cost(edge, area.as_ref()))
+        {
+            if cells.len() == max_cells {
+                is_truncated = true;
+                break;
+            }
+            cells.push(Reached::new(cell, node.cost, node.source));
+        }
+
+        Ok(Reachability::new(cells, is_truncated))
+    }
+
+    /// Best-first search from `src` to `dst`, guided by the given heuristic.
+    fn route(
+        &mut self,
+        src: CellIndex,
+        dst: CellIndex,
+        heuristic: impl Fn(CellIndex) -> f64,
+    ) -> Result<Option<Route>, ResolutionMismatch> {
+        if src.resolution() != dst.resolution() {
+            return Err(ResolutionMismatch);
+        }
+        if self.blocked.contains(&src) || self.blocked.contains(&dst) {
+            return Ok(None);
+        }
+        let area = self.area(&[src]);
+        if area.as_ref().is_some_and(|area| !area.contains(&dst)) {
+            return Ok(None);
+        }
+
+        let max_cells = self.max_cells.unwrap_or(usize::MAX);
+        let mut search = Search::new([src], heuristic, self.cost_limit());
+        let mut count = 0;
+        while let Some((cell, node)) =
+            search.next(|edge| self.edge_cost(edge, area.as_ref()))
+        {
+            if count == max_cells {
+                break;
+            }
+            if cell == dst {
+                return Ok(Some(Route::new(
+                    src,
+                    node.cost,
+                    search.edges_to(dst),
+                )));
+            }
+            count += 1;
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the area allowed by the search radius, if any.
+    fn area(&self, sources: &[CellIndex]) -> Option<HashSet<CellIndex>> {
+        self.max_radius.map(|k| {
+            sources
+                .iter()
+                .flat_map(|source| source.grid_disk::<Vec<_>>(k))
+                .collect()
+        })
+    }
+
+    /// Returns the max allowed cost.
+    fn cost_limit(&self) -> f64 {
+        self.max_cost.unwrap_or(f64::INFINITY)
+    }
+
+    /// Returns the cost of crossing the edge, if allowed.
+    fn edge_cost(
+        &mut self,
+        edge: DirectedEdgeIndex,
+        area: Option<&HashSet<CellIndex>>,
+    ) -> Option<f64> {
+        let neighbor = edge.destination();
+        if self.blocked.contains(&neighbor)
+            || area.is_some_and(|area| !area.contains(&neighbor))
+        {
+            return None;
+        }
+        let cost = (self.cost)(edge);
+        cost.is_finite().then_some(cost)
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// A route between two cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    /// Traversed cells, from source to destination (inclusive).
+    cells: Vec<CellIndex>,
+    /// Traversed edges, from source to destination.
+    edges: Vec<DirectedEdgeIndex>,
+    /// Total cost of the route.
+    cost: f64,
+}
+
+impl Route {
+    fn new(src: CellIndex, cost: f64, edges: Vec<DirectedEdgeIndex>) -> Self {
+        let cells = std::iter::once(src)
+            .chain(edges.iter().map(|edge| edge.destination()))
+            .collect();
+
+        Self { cells, edges, cost }
+    }
+
+    /// Returns the traversed cells, from source to destination (inclusive).
+    #[must_use]
+    pub fn cells(&self) -> &[CellIndex] {
+        &self.cells
+    }
+
+    /// Returns the traversed edges, from source to destination.
+    #[must_use]
+    pub fn edges(&self) -> &[DirectedEdgeIndex] {
+        &self.edges
+    }
+
+    /// Returns the total cost of the route.
+    #[must_use]
+    pub const fn cost(&self) -> f64 {
+        self.cost
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{CellIndex, DirectedEdgeIndex};
+use ahash::{HashMap, HashMapExt};
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+/// Multi-source best-first search over the cell grid.
+///
+/// With a null heuristic this is Dijkstra's algorithm, otherwise it's A*.
+pub struct Search<H> {
+    /// Guides the search toward the target (if any).
+    heuristic: H,
+    /// Upper bound of the cost of the visited cells.
+    max_cost: f64,
+
+    /// Best known state of every visited cell.
+    visited: HashMap<CellIndex, Node>,
+    /// Cells to visit.
+    queue: BinaryHeap<Entry>,
+}
+
+impl<H> Search<H>
+where
+    H: Fn(CellIndex) -> f64,
+{
+    /// Initializes a new search, starting from the given cells.
+    pub fn new(
+        sources: impl IntoIterator<Item = CellIndex>,
+        heuristic: H,
+        max_cost: f64,
+    ) -> Self {
+        let mut visited = HashMap::new();
+        let mut queue = BinaryHeap::new();
+        for source in sources {
+            let node = Node {
+                cost: 0.,
+                edge: None,
+                source,
+                is_settled: false,
+            };
+            if visited.insert(source, node).is_none() {
+                queue.push(Entry {
+                    priority: heuristic(source),
+                    cost: 0.,
+                    cell: source,
+                });
+            }
+        }
+
+        Self {
+            heuristic,
+            max_cost,
+            visited,
+            queue,
+        }
+    }
+
+    /// Settles the next cheapest cell and relaxes its outgoing edges.
+    ///
+    /// `edge_cost` returns the cost of crossing an edge, or `None` if the edge
+    /// cannot be crossed.
+    pub fn next(
+        &mut self,
+        mut edge_cost: impl FnMut(DirectedEdgeIndex) -> Option<f64>,
+    ) -> Option<(CellIndex, Node)> {
+        while let Some(Entry { cost, cell, .. }) = self.queue.pop() {
+            let node = self.visited.get_mut(&cell).expect("visited cell");
+            // Skip outdated entries.
+            if node.is_settled || cost > node.cost {
+                continue;
+            }
+            node.is_settled = true;
+            let node = *node;
+
+            for edge in cell.edges() {
+                let Some(edge_cost) = edge_cost(edge) else {
+                    continue;
+                };
+                debug_assert!(edge_cost >= 0., "negative cost");
+
+                let new_cost = cost + edge_cost;
+                if new_cost > self.max_cost {
+                    continue;
+                }
+                let neighbor = edge.destination();
+                let is_better = self
+                    .visited
+                    .get(&neighbor)
+                    .is_none_or(|best| new_cost < best.cost);
+                if is_better {
+                    self.visited.insert(
+                        neighbor,
+                        Node {
+                            cost: new_cost,
+                            edge: Some(edge),
+                            source: node.source,
+                            is_settled: false,
+                        },
+                    );
+                    self.queue.push(Entry {
+                        priority: new_cost + (self.heuristic)(neighbor),
+                        cost: new_cost,
+                        cell: neighbor,
+                    });
+                }
+            }
+
+            return Some((cell, node));
+        }
+
+        None
+    }
+
+    /// Returns the edges leading from the source to the given visited cell.
+    pub fn edges_to(&self, mut cell: CellIndex) -> Vec<DirectedEdgeIndex> {
+        let mut edges = Vec::new();
+        while let Some(edge) = self.visited[&cell].edge {
+            edges.push(edge);
+            cell = edge.origin();
+        }
+        edges.reverse();
+        edges
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// State of a visited cell.
+#[derive(Debug, Clone, Copy)]
+pub struct Node {
+    /// Cost to reach the cell.
+    pub cost: f64,
+    /// Edge used to reach the cell (none for the sources).
+    pub edge: Option<DirectedEdgeIndex>,
+    /// Source from which the cell has been reached.
+    pub source: CellIndex,
+    /// Whether the cost of the cell is final or not.
+    is_settled: bool,
+}
+
+// -----------------------------------------------------------------------------
+
+/// An entry of the priority queue.
+///
+/// Ordering is reversed so that `BinaryHeap` behaves as a min-heap, ties are
+/// broken on the cell index to keep the search deterministic.
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    /// Priority of the cell (lower is better).
+    priority: f64,
+    /// Cost to reach the cell.
+    cost: f64,
+    /// Cell to visit.
+    cell: CellIndex,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .total_cmp(&self.priority)
+            .then_with(|| other.cell.cmp(&self.cell))
+    }
+}
@@ -0,0 +1,188 @@
+//! Conversion of trajectories (e.g. GPS traces) into sequences of cells.
+//!
+//! Every point of the trajectory is mapped to its cell and the path between
+//! two consecutive points is traced along the great circle arc joining them:
+//! no cell is skipped, even for sparse traces, and the entry and exit times of
+//! every cell are interpolated at the boundary crossings.
+//!
+//! Timestamps can use any unit (e.g. seconds since the Unix epoch), as long as
+//! they are increasing along the trajectory.
+//!
+//! ```
+//! use h3o::{trajectory::Trajectory, LatLng, Resolution};
+//!
+//! let mut trajectory = Trajectory::new(Resolution::Nine);
+//! trajectory.push(LatLng::new(48.864716, 2.349014)?, 0.);
+//! trajectory.push(LatLng::new(48.858093, 2.294694)?, 900.);
+//! for visit in trajectory.visits() {
+//!     println!("{}: {}s", visit.cell(), visit.dwell());
+//! }
+//! # Ok::<(), h3o::error::InvalidLatLng>(())
+//! ```
+
+use crate::{grid, CellIndex, LatLng, Resolution};
+
+/// A stay inside a cell.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Visit {
+    /// Visited cell.
+    cell: CellIndex,
+    /// When the cell has been entered.
+    entry: f64,
+    /// When the cell has been left.
+    exit: f64,
+}
+
+impl Visit {
+    /// Returns the visited cell.
+    #[must_use]
+    pub const fn cell(&self) -> CellIndex {
+        self.cell
+    }
+
+    /// Returns when the cell has been entered.
+    #[must_use]
+    pub const fn entry(&self) -> f64 {
+        self.entry
+    }
+
+    /// Returns when the cell has been left.
+    ///
+    /// For the last visit of a trajectory, this is the time of the last point.
+    #[must_use]
+    pub const fn exit(&self) -> f64 {
+        self.exit
+    }
+
+    /// Returns the time spent inside the cell.
+    #[must_use]
+    pub fn dwell(&self) -> f64 {
+        self.exit - self.entry
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// A trajectory, as a sequence of visited cells.
+///
+/// Consecutive points in the same cell are collapsed into a single visit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trajectory {
+    /// Resolution of the visited cells.
+    resolution: Resolution,
+    /// Last point of the trajectory, with its timestamp.
+    last: Option<(LatLng, f64)>,
+    /// Visited cells, in order.
+    visits: Vec<Visit>,
+}
+
+impl Trajectory {
+    /// Initializes a new, empty, trajectory at the given resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{trajectory::Trajectory, Resolution};
+    ///
+    /// let trajectory = Trajectory::new(Resolution::Nine);
+    /// ```
+    #[must_use]
+    pub const fn new(resolution: Resolution) -> Self {
+        Self {
+            resolution,
+            last: None,
+            visits: Vec::new(),
+        }
+    }
+
+    /// Appends a point, visited at the given time, to the trajectory.
+    ///
+    /// The cells between the previous point and this one are traced along the
+    /// great circle arc joining them, with entry and exit times interpolated
+    /// proportionally to the traveled distance.
+    ///
+    /// Timestamps are expected to increase: a point earlier than the previous
+    /// one is considered visited at the time of the previous one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{trajectory::Trajectory, LatLng, Resolution};
+    ///
+    /// let mut trajectory = Trajectory::new(Resolution::Nine);
+    /// trajectory.push(LatLng::new(48.864716, 2.349014)?, 0.);
+    /// trajectory.push(LatLng::new(48.858093, 2.294694)?, 900.);
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    pub fn push(&mut self, point: LatLng, time: f64) {
+        // Samples can't go back in time.
+        let time = self.last.map_or(time, |(_, previous)| time.max(previous));
+        let Some((previous, previous_time)) = self.last.replace((point, time))
+        else {
+            self.visits.push(Visit {
+                cell: point.to_cell(self.resolution),
+                entry: time,
+                exit: time,
+            });
+            return;
+        };
+
+        let distance = previous.distance_rads(point);
+        let duration = time - previous_time;
+        for crossing in grid::crossings(previous, point, self.resolution) {
+            let ratio = if distance > 0. {
+                previous.distance_rads(crossing.point) / distance
+            } else {
+                0.
+            };
+            self.enter(
+                crossing.edge.destination(),
+                ratio.mul_add(duration, previous_time),
+            );
+        }
+
+        if let Some(visit) = self.visits.last_mut() {
+            visit.exit = time;
+        }
+    }
+
+    /// Returns the visited cells, in order.
+    ///
+    /// A cell appears once per stay, the same cell can be visited several
+    /// times.
+    #[must_use]
+    pub fn visits(&self) -> &[Visit] {
+        &self.visits
+    }
+
+    /// Returns the visited cells, in order.
+    ///
+    /// See [`Trajectory::visits`].
+    #[must_use]
+    pub fn into_visits(self) -> Vec<Visit> {
+        self.visits
+    }
+
+    /// Leaves the current cell to enter the given one, at the given time.
+    fn enter(&mut self, cell: CellIndex, time: f64) {
+        if let Some(visit) = self.visits.last_mut() {
+            if visit.cell == cell {
+                return;
+            }
+            visit.exit = time;
+        }
+        self.visits.push(Visit {
+            cell,
+            entry: time,
+            exit: time,
+        });
+    }
+}
+
+impl Extend<(LatLng, f64)> for Trajectory {
+    fn extend<T: IntoIterator<Item = (LatLng, f64)>>(&mut self, iter: T) {
+        for (point, time) in iter {
+            self.push(point, time);
+        }
+    }
+}
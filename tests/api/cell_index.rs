@@ -99,3 +99,49 @@ fn child_position_roundtrip() {
 
     assert_eq!(cell, Some(child));
 }
+
+#[test]
+fn grid_distance_safe() {
+    // Not neighboring base cells, around a pentagon.
+    let src = CellIndex::try_from(0x81083ffffffffff).expect("src");
+    let dst = CellIndex::try_from(0x810a3ffffffffff).expect("dst");
+    assert!(src.grid_distance(dst).is_err());
+    assert_eq!(src.grid_distance_safe(dst), Ok(5));
+
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let dst = CellIndex::try_from(0x8a1fb4644937fff).expect("dst");
+    assert_eq!(
+        src.grid_distance_safe(dst),
+        src.grid_distance(dst)
+            .map_err(|_| error::ResolutionMismatch)
+    );
+
+    assert_eq!(
+        src.grid_distance_safe(dst.parent(Resolution::Six).expect("parent")),
+        Err(error::ResolutionMismatch)
+    );
+}
+
+#[test]
+fn grid_path_cells_safe() {
+    let src = CellIndex::try_from(0x81083ffffffffff).expect("src");
+    let dst = CellIndex::try_from(0x810a3ffffffffff).expect("dst");
+    let path = src
+        .grid_path_cells_safe(dst)
+        .expect("path")
+        .collect::<Vec<_>>();
+
+    assert_eq!(path.len(), 6);
+    assert_eq!(path.first(), Some(&src));
+    assert_eq!(path.last(), Some(&dst));
+    for pair in path.windows(2) {
+        assert_eq!(pair[0].is_neighbor_with(pair[1]), Ok(true));
+    }
+
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let path = cell
+        .grid_path_cells_safe(cell)
+        .expect("path")
+        .collect::<Vec<_>>();
+    assert_eq!(path, vec![cell]);
+}
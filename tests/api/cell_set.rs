@@ -0,0 +1,298 @@
+use h3o::{cell_set, error::ResolutionMismatch, CellIndex, LatLng, Resolution};
+
+#[test]
+fn components() {
+    let cell1 = CellIndex::try_from(0x8a1fb46622dffff).expect("cell1");
+    let cell2 = cell1.grid_ring_fast(5).next().flatten().expect("cell2");
+    let mut cells = cell1.grid_disk::<Vec<_>>(2);
+    cells.extend(cell2.grid_disk::<Vec<_>>(1));
+
+    let result = cell_set::components(cells.clone()).expect("components");
+
+    assert_eq!(result.count(), 2);
+    assert_ne!(result.label(cell1), result.label(cell2));
+    for cell in cell1.grid_disk::<Vec<_>>(2) {
+        assert_eq!(result.label(cell), result.label(cell1));
+    }
+    for cell in cell2.grid_disk::<Vec<_>>(1) {
+        assert_eq!(result.label(cell), result.label(cell2));
+    }
+    assert_eq!(result.iter().count(), cells.len());
+
+    let id = result.label(cell2).expect("label");
+    assert_eq!(result.component(id).count(), 7);
+}
+
+#[test]
+fn components_ring() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let ring = cell.grid_ring_fast(2).collect::<Option<Vec<_>>>();
+
+    let result = cell_set::components(ring.expect("ring")).expect("components");
+
+    assert_eq!(result.count(), 1);
+    assert_eq!(result.label(cell), None);
+}
+
+#[test]
+fn components_pentagon() {
+    let pentagon = Resolution::Six.pentagons().next().expect("pentagon");
+    let ring = pentagon.grid_disk_safe(2).filter(|&cell| cell != pentagon);
+
+    let result = cell_set::components(ring).expect("components");
+
+    assert_eq!(result.count(), 1);
+}
+
+#[test]
+fn components_duplicates() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+
+    let result = cell_set::components([cell, cell]).expect("components");
+
+    assert_eq!(result.count(), 1);
+    assert_eq!(result.iter().count(), 1);
+}
+
+#[test]
+fn components_resolution_mismatch() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let parent = cell.parent(Resolution::Five).expect("parent");
+
+    assert_eq!(
+        cell_set::components([cell, parent]),
+        Err(ResolutionMismatch)
+    );
+    assert_eq!(cell_set::components([]).expect("empty").count(), 0);
+}
+
+#[test]
+fn dilate_erode() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let mut disk = cell.grid_disk::<Vec<_>>(3);
+    disk.sort_unstable();
+
+    let dilated = cell_set::dilate([cell], 3).expect("dilate");
+    assert_eq!(dilated, disk);
+
+    assert_eq!(cell_set::erode(disk.clone(), 3).expect("erode"), vec![cell]);
+    assert!(cell_set::erode(disk.clone(), 4).expect("erode").is_empty());
+    assert_eq!(cell_set::erode(disk.clone(), 0).expect("erode"), disk);
+}
+
+#[test]
+fn dilate_erode_pentagon() {
+    let pentagon = Resolution::Six.pentagons().next().expect("pentagon");
+    let mut disk = pentagon.grid_disk::<Vec<_>>(3);
+    disk.sort_unstable();
+
+    assert_eq!(cell_set::dilate([pentagon], 3).expect("dilate"), disk);
+    assert_eq!(
+        cell_set::erode(disk.clone(), 3).expect("erode"),
+        vec![pentagon]
+    );
+}
+
+#[test]
+fn open_close() {
+    let cell1 = CellIndex::try_from(0x8a1fb46622dffff).expect("cell1");
+    let cell2 = cell1.grid_ring_fast(5).next().flatten().expect("cell2");
+    let mut disk = cell1.grid_disk::<Vec<_>>(2);
+    disk.sort_unstable();
+
+    // The isolated cell is removed, the disk is kept.
+    let mut cells = disk.clone();
+    cells.push(cell2);
+    assert_eq!(cell_set::open(cells, 1).expect("open"), disk);
+
+    // The hole is filled.
+    let cells = disk.iter().copied().filter(|&cell| cell != cell1);
+    assert_eq!(cell_set::close(cells, 1).expect("close"), disk);
+}
+
+#[test]
+fn fill_holes() {
+    let cell1 = CellIndex::try_from(0x8a1fb46622dffff).expect("cell1");
+    let cell2 = cell1.grid_ring_fast(10).next().flatten().expect("cell2");
+    let mut disk = cell1.grid_disk::<Vec<_>>(3);
+    disk.sort_unstable();
+
+    // A ring around a hole of 19 cells, plus an unrelated cell.
+    let mut cells = cell1
+        .grid_disk::<Vec<_>>(3)
+        .into_iter()
+        .filter(|cell| cell1.grid_distance(*cell).expect("distance") > 2)
+        .collect::<Vec<_>>();
+    cells.push(cell2);
+    let mut expected = disk.clone();
+    expected.push(cell2);
+    expected.sort_unstable();
+
+    assert_eq!(cell_set::fill_holes(cells).expect("fill"), expected);
+    assert_eq!(cell_set::fill_holes(disk.clone()).expect("fill"), disk);
+    assert!(cell_set::fill_holes([]).expect("fill").is_empty());
+}
+
+#[test]
+fn fill_holes_pentagon() {
+    let pentagon = Resolution::Six.pentagons().next().expect("pentagon");
+    let mut disk = pentagon.grid_disk::<Vec<_>>(2);
+    disk.sort_unstable();
+    let ring = disk.iter().copied().filter(|&cell| cell != pentagon);
+
+    assert_eq!(cell_set::fill_holes(ring).expect("fill"), disk);
+}
+
+#[test]
+fn fill_holes_largest_outside() {
+    // A thin ring, larger than a deep disk: the whole grid is set, except
+    // those two groups of cells.
+    let cell = |lat, lng| {
+        LatLng::new(lat, lng)
+            .expect("coordinate")
+            .to_cell(Resolution::One)
+    };
+    let disk = cell(40., 0.).grid_disk::<Vec<_>>(2);
+    let inner = cell(-30., 80.).grid_disk::<Vec<_>>(3);
+    let ring = cell(-30., 80.)
+        .grid_disk::<Vec<_>>(4)
+        .into_iter()
+        .filter(|cell| !inner.contains(cell))
+        .collect::<Vec<_>>();
+    assert!(ring.len() > disk.len());
+    assert!(ring.iter().all(|cell| !disk.contains(cell)));
+    let cells = CellIndex::base_cells()
+        .flat_map(|cell| cell.children(Resolution::One))
+        .filter(|cell| !disk.contains(cell) && !ring.contains(cell))
+        .collect::<Vec<_>>();
+    let mut expected = cells.clone();
+    expected.extend(disk);
+    expected.sort_unstable();
+
+    assert_eq!(cell_set::fill_holes(cells).expect("fill"), expected);
+}
+
+#[test]
+fn boundary_interior() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let disk = cell.grid_disk::<Vec<_>>(2);
+    let mut ring = cell
+        .grid_ring_fast(2)
+        .collect::<Option<Vec<_>>>()
+        .expect("ring");
+    ring.sort_unstable();
+    let mut inner = cell.grid_disk::<Vec<_>>(1);
+    inner.sort_unstable();
+
+    assert_eq!(cell_set::boundary(disk.clone()).expect("boundary"), ring);
+    assert_eq!(cell_set::interior(disk).expect("interior"), inner);
+}
+
+#[test]
+fn morphology_resolution_mismatch() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let parent = cell.parent(Resolution::Five).expect("parent");
+
+    assert_eq!(cell_set::dilate([cell, parent], 1), Err(ResolutionMismatch));
+    assert_eq!(cell_set::erode([cell, parent], 1), Err(ResolutionMismatch));
+    assert_eq!(
+        cell_set::fill_holes([cell, parent]),
+        Err(ResolutionMismatch)
+    );
+    assert_eq!(cell_set::boundary([cell, parent]), Err(ResolutionMismatch));
+}
+
+#[test]
+fn nearest_seeds() {
+    let seed1 = CellIndex::try_from(0x8a1fb46622dffff).expect("seed1");
+    let seed2 = seed1.grid_ring_fast(6).next().flatten().expect("seed2");
+
+    let result =
+        cell_set::nearest_seeds([seed2, seed1], None, Some(4)).expect("bfs");
+
+    let mut cells = seed1.grid_disk::<Vec<_>>(4);
+    cells.extend(seed2.grid_disk::<Vec<_>>(4));
+    cells.sort_unstable();
+    cells.dedup();
+    assert_eq!(result.len(), cells.len());
+    assert!(result.windows(2).all(|pair| pair[0].2 <= pair[1].2));
+    for (cell, seed, distance) in result {
+        let distance1 = seed1.grid_distance(cell).expect("distance1");
+        let distance2 = seed2.grid_distance(cell).expect("distance2");
+        let expected = match distance1.cmp(&distance2) {
+            std::cmp::Ordering::Less => seed1,
+            std::cmp::Ordering::Greater => seed2,
+            std::cmp::Ordering::Equal => seed1.min(seed2),
+        };
+        assert_eq!(seed, expected, "{cell}");
+        assert_eq!(i32::try_from(distance), Ok(distance1.min(distance2)));
+    }
+}
+
+#[test]
+fn nearest_seeds_region() {
+    let seed = CellIndex::try_from(0x8a1fb46622dffff).expect("seed");
+    let outside = seed.grid_ring_fast(4).next().flatten().expect("outside");
+    // A disk with a wall, the cells behind the wall must go around.
+    let wall = seed
+        .grid_ring_fast(1)
+        .collect::<Option<Vec<_>>>()
+        .expect("ring");
+    let region = seed
+        .grid_disk::<Vec<_>>(3)
+        .into_iter()
+        .filter(|cell| !wall[1..].contains(cell))
+        .collect::<Vec<_>>();
+
+    let result = cell_set::nearest_seeds([seed, outside], Some(&region), None)
+        .expect("bfs");
+
+    assert_eq!(result.len(), region.len());
+    let distance = |target| {
+        result.iter().find_map(|&(cell, _, distance)| {
+            (cell == target).then_some(distance)
+        })
+    };
+    assert_eq!(distance(seed), Some(0));
+    assert_eq!(distance(wall[0]), Some(1));
+    assert!(distance(outside).is_none());
+    assert!(result.iter().all(|&(_, nearest, _)| nearest == seed));
+    assert!(result.iter().any(|&(cell, _, distance)| {
+        let expected = seed.grid_distance(cell).expect("distance");
+        i32::try_from(distance).is_ok_and(|distance| distance > expected)
+    }));
+}
+
+#[test]
+fn nearest_seeds_pentagon() {
+    let pentagon = Resolution::Six.pentagons().next().expect("pentagon");
+
+    let result =
+        cell_set::nearest_seeds([pentagon], None, Some(3)).expect("bfs");
+
+    let mut disk = pentagon.grid_disk::<Vec<_>>(3);
+    disk.sort_unstable();
+    let mut cells = result.iter().map(|&(cell, _, _)| cell).collect::<Vec<_>>();
+    cells.sort_unstable();
+    assert_eq!(cells, disk);
+    for (cell, _, distance) in result {
+        let expected = pentagon.grid_distance_safe(cell).expect("distance");
+        assert_eq!(i32::try_from(distance), Ok(expected));
+    }
+}
+
+#[test]
+fn nearest_seeds_resolution_mismatch() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let parent = cell.parent(Resolution::Five).expect("parent");
+
+    assert_eq!(
+        cell_set::nearest_seeds([cell, parent], None, Some(1)),
+        Err(ResolutionMismatch)
+    );
+    assert_eq!(
+        cell_set::nearest_seeds([cell], Some(&[parent]), None),
+        Err(ResolutionMismatch)
+    );
+    assert_eq!(cell_set::nearest_seeds([], None, None), Ok(Vec::new()));
+}
@@ -0,0 +1,78 @@
+use h3o::{
+    geom::{Circle, ToCells},
+    CellIndex, LatLng, Resolution,
+};
+use std::collections::BTreeSet;
+
+/// Cells whose center is within the radius, by exhaustive search.
+fn brute_force(
+    center: LatLng,
+    radius: f64,
+    resolution: Resolution,
+    k: u32,
+) -> BTreeSet<CellIndex> {
+    center
+        .to_cell(resolution)
+        .grid_disk::<Vec<_>>(k)
+        .into_iter()
+        .filter(|&cell| LatLng::from(cell).distance_m(center) <= radius)
+        .collect()
+}
+
+fn check(center: LatLng, radius: f64, resolution: Resolution, k: u32) {
+    let circle = Circle::new(center, radius).expect("circle");
+    let result = circle.to_cells(resolution).collect::<Vec<_>>();
+    let cells = result.iter().copied().collect::<BTreeSet<_>>();
+
+    assert_eq!(cells.len(), result.len(), "no duplicate");
+    assert_eq!(cells, brute_force(center, radius, resolution, k));
+    assert!(result.len() <= circle.max_cells_count(resolution));
+}
+
+#[test]
+fn to_cells() {
+    let center = LatLng::new(48.864716, 2.349014).expect("center");
+
+    check(center, 5_000., Resolution::Seven, 10);
+    check(center, 500., Resolution::Nine, 10);
+}
+
+#[test]
+fn to_cells_antimeridian() {
+    let center = LatLng::new(-16.5, 179.99).expect("center");
+
+    check(center, 20_000., Resolution::Six, 10);
+}
+
+#[test]
+fn to_cells_pole() {
+    let center = LatLng::new(89.99, 42.).expect("center");
+
+    check(center, 50_000., Resolution::Five, 10);
+}
+
+#[test]
+fn to_cells_pentagon() {
+    let pentagon = CellIndex::try_from(0x85080003fffffff).expect("pentagon");
+
+    check(LatLng::from(pentagon), 30_000., Resolution::Five, 6);
+}
+
+#[test]
+fn to_cells_tiny() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let center = LatLng::from(cell);
+    let circle = Circle::new(center, 1.).expect("circle");
+    let result = circle.to_cells(Resolution::Ten).collect::<Vec<_>>();
+
+    assert_eq!(result, vec![cell]);
+}
+
+#[test]
+fn invalid() {
+    let center = LatLng::new(48.864716, 2.349014).expect("center");
+
+    assert!(Circle::new(center, -1.).is_err());
+    assert!(Circle::new(center, f64::NAN).is_err());
+    assert!(Circle::new(center, f64::INFINITY).is_err());
+}
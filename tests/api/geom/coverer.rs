@@ -0,0 +1,161 @@
+use geo::{line_string, point, polygon, Contains};
+use h3o::{
+    geom::{
+        Circle, Coverable, Coverer, Geometry, LineString, Polygon, ToCells,
+    },
+    LatLng, Resolution,
+};
+use std::collections::BTreeSet;
+
+fn paris() -> Polygon<'static> {
+    let polygon: geo::Polygon<f64> = polygon![
+        (x: 2.2, y: 48.8), (x: 2.4, y: 48.8), (x: 2.45, y: 48.9),
+        (x: 2.3, y: 48.95), (x: 2.2, y: 48.9), (x: 2.2, y: 48.8),
+    ];
+    Polygon::from_degrees(polygon).expect("polygon")
+}
+
+fn check(geometry: &impl Coverable, coverer: &Coverer, max_cells: usize) {
+    let (min, max) = (Resolution::Five, Resolution::Nine);
+    let cells = geometry.to_cells(max).collect::<BTreeSet<_>>();
+
+    let covering = coverer.covering(geometry);
+    assert!(covering.len() <= max_cells);
+    assert!(covering.windows(2).all(|pair| pair[0] < pair[1]), "sorted");
+    assert!(covering
+        .iter()
+        .all(|cell| (min..=max).contains(&cell.resolution())));
+    let covered = covering
+        .iter()
+        .flat_map(|cell| cell.children(max))
+        .collect::<BTreeSet<_>>();
+    assert!(covered.is_superset(&cells), "covering");
+
+    let interior = coverer.interior_covering(geometry);
+    assert!(interior.len() <= max_cells);
+    assert!(interior.windows(2).all(|pair| pair[0] < pair[1]), "sorted");
+    assert!(interior
+        .iter()
+        .all(|cell| (min..=max).contains(&cell.resolution())));
+    let covered = interior
+        .iter()
+        .flat_map(|cell| cell.children(max))
+        .collect::<BTreeSet<_>>();
+    assert!(covered.is_subset(&cells), "interior");
+}
+
+#[test]
+fn covering() {
+    let coverer = Coverer::new(Resolution::Five, Resolution::Nine, 20);
+    let circle = Circle::new(LatLng::new(48.86, 2.35).expect("center"), 5_000.)
+        .expect("circle");
+    let line: geo::LineString<f64> =
+        line_string![(x: 2.2, y: 48.8), (x: 2.4, y: 48.9)];
+    let line = LineString::from_degrees(line).expect("line");
+
+    check(&paris(), &coverer, 20);
+    check(&circle, &coverer, 20);
+    check(&line, &coverer, 20);
+}
+
+#[test]
+fn covering_collection() {
+    let coverer = Coverer::new(Resolution::Five, Resolution::Nine, 20);
+    let collection = geo::Geometry::GeometryCollection(
+        geo::GeometryCollection::new_from(vec![
+            geo::Geometry::Line(geo::Line::new(
+                (2.5_f64.to_radians(), 48.7_f64.to_radians()),
+                (2.6_f64.to_radians(), 48.75_f64.to_radians()),
+            )),
+            geo::Geometry::Polygon(paris().into()),
+        ]),
+    );
+    let collection = Geometry::from_radians(&collection).expect("collection");
+
+    check(&collection, &coverer, 20);
+}
+
+#[test]
+fn covering_swapped_resolutions() {
+    let coverer = Coverer::new(Resolution::Nine, Resolution::Five, 20);
+
+    check(&paris(), &coverer, 20);
+}
+
+#[test]
+fn covering_tighter_with_budget() {
+    let polygon = paris();
+    let waste = |max_cells| {
+        Coverer::new(Resolution::Five, Resolution::Nine, max_cells)
+            .covering(&polygon)
+            .iter()
+            .map(|cell| cell.children_count(Resolution::Nine))
+            .sum::<u64>()
+    };
+
+    assert!(waste(100) < waste(10));
+}
+
+#[test]
+fn covering_unbounded_is_exact() {
+    let polygon = paris();
+    let coverer = Coverer::new(Resolution::Zero, Resolution::Nine, usize::MAX);
+    let cells = polygon.to_cells(Resolution::Nine).collect::<BTreeSet<_>>();
+    let covering = coverer.covering(&polygon);
+    let covered = covering
+        .iter()
+        .flat_map(|cell| cell.children(Resolution::Nine))
+        .collect::<BTreeSet<_>>();
+
+    assert_eq!(covered, cells);
+    assert!(covering.len() < cells.len());
+    assert_eq!(coverer.interior_covering(&polygon), covering);
+}
+
+#[test]
+fn covering_large_polygon() {
+    // Too many cells to be listed at the maximum resolution.
+    let polygon: geo::Polygon<f64> = polygon![
+        (x: -4.5, y: 43.5), (x: 7.5, y: 43.5), (x: 8., y: 49.),
+        (x: 2.5, y: 51.), (x: -4.5, y: 48.5), (x: -4.5, y: 43.5),
+    ];
+    let shape = polygon.clone();
+    let polygon = Polygon::from_degrees(polygon).expect("polygon");
+    let coverer = Coverer::new(Resolution::Two, Resolution::Twelve, 64);
+
+    let covering = coverer.covering(&polygon);
+    assert!(covering.len() <= 64);
+    for (lat, lng) in [(48.86, 2.35), (45.76, 4.83), (43.6, -1.4)] {
+        let cell = LatLng::new(lat, lng)
+            .expect("coordinate")
+            .to_cell(Resolution::Twelve);
+        assert!(
+            covering
+                .iter()
+                .any(|&ancestor| cell.parent(ancestor.resolution())
+                    == Some(ancestor)),
+            "({lat}, {lng}) is covered"
+        );
+    }
+
+    let interior = coverer.interior_covering(&polygon);
+    assert!(!interior.is_empty());
+    assert!(interior.len() <= 64);
+    for cell in interior {
+        let center = LatLng::from(cell);
+        assert!(shape.contains(&point!(x: center.lng(), y: center.lat())));
+    }
+}
+
+#[test]
+fn covering_empty() {
+    let polygon: geo::Polygon<f64> = polygon![
+        (x: 2.35, y: 48.86), (x: 2.3501, y: 48.86), (x: 2.35, y: 48.8601),
+        (x: 2.35, y: 48.86),
+    ];
+    let polygon = Polygon::from_degrees(polygon).expect("polygon");
+    let coverer = Coverer::new(Resolution::Zero, Resolution::Five, 100);
+
+    assert!(coverer.covering(&polygon).is_empty());
+    assert!(coverer.interior_covering(&polygon).is_empty());
+}
@@ -163,3 +163,44 @@ fn to_cells() {
 
     assert!(result <= bound);
 }
+
+#[test]
+fn try_to_cells() {
+    let geom = GeometryCollection::from_degrees(geometrycollection_degs())
+        .expect("geom");
+    let bound = geom.max_cells_count(Resolution::Two);
+
+    assert!(geom.try_to_cells(Resolution::Two, bound).is_ok());
+    let err = geom
+        .try_to_cells(Resolution::Two, bound - 1)
+        .err()
+        .expect("too many cells");
+    assert_eq!(err.count(), bound);
+}
+
+#[test]
+fn to_sorted_cells() {
+    let square = polygon![
+        (x: 2.2, y: 48.8), (x: 2.4, y: 48.8), (x: 2.4, y: 48.9),
+        (x: 2.2, y: 48.9), (x: 2.2, y: 48.8),
+    ];
+    let inner = geo::GeometryCollection::new_from(vec![
+        geo::Geometry::Point(point!(x: 2.3, y: 48.85)),
+        geo::Geometry::Polygon(square.clone()),
+    ]);
+    let shape = geo::GeometryCollection::new_from(vec![
+        geo::Geometry::Polygon(square),
+        geo::Geometry::LineString(line_string![
+            (x: 2.1, y: 48.85), (x: 2.5, y: 48.85),
+        ]),
+        geo::Geometry::GeometryCollection(inner),
+    ]);
+    let geom = GeometryCollection::from_degrees(shape).expect("geom");
+    let mut expected = geom.to_cells(Resolution::Eight).collect::<Vec<_>>();
+    expected.sort_unstable();
+    expected.dedup();
+
+    let result = geom.to_sorted_cells(Resolution::Eight).collect::<Vec<_>>();
+
+    assert_eq!(result, expected);
+}
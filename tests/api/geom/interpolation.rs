@@ -0,0 +1,129 @@
+use h3o::{
+    geom::{areal_interpolation, Attribute, Feature, Polygon},
+    CellIndex, Resolution,
+};
+
+fn cell_feature(cell: CellIndex, values: Vec<f64>) -> Feature<'static> {
+    let ring = cell
+        .boundary()
+        .iter()
+        .map(|ll| geo::coord! { x: ll.lng(), y: ll.lat() })
+        .collect::<geo::LineString>();
+    let polygon = Polygon::from_degrees(geo::Polygon::new(ring, Vec::new()))
+        .expect("polygon");
+    Feature::new(polygon.into(), values)
+}
+
+#[test]
+fn extensive_preserves_total() {
+    let cell = CellIndex::try_from(0x851fb467fffffff).expect("cell index");
+    let features = [cell_feature(cell, vec![1000.])];
+
+    let result = areal_interpolation(
+        &features,
+        &[Attribute::Extensive],
+        Resolution::Seven,
+    );
+    let total = result.values().map(|values| values[0]).sum::<f64>();
+
+    assert!(result.len() > 49);
+    assert!((total - 1000.).abs() < 1e-6);
+}
+
+#[test]
+fn intensive_uniform() {
+    let cell = CellIndex::try_from(0x851fb467fffffff).expect("cell index");
+    let features = [cell_feature(cell, vec![0.5])];
+
+    let result = areal_interpolation(
+        &features,
+        &[Attribute::Intensive],
+        Resolution::Seven,
+    );
+
+    assert!(result.values().all(|values| (values[0] - 0.5).abs() < 1e-9));
+}
+
+#[test]
+fn intensive_weighted_average() {
+    // Two adjacent features, with a coarse cell straddling both.
+    let cell = CellIndex::try_from(0x861fb4667ffffff).expect("cell index");
+    let [a, b] =
+        cell.children(Resolution::Seven).take(2).collect::<Vec<_>>()[..]
+    else {
+        unreachable!()
+    };
+    let features = [
+        cell_feature(a, vec![1., 10.]),
+        cell_feature(b, vec![3., 20.]),
+    ];
+
+    let result = areal_interpolation(
+        &features,
+        &[Attribute::Intensive, Attribute::Extensive],
+        Resolution::Six,
+    );
+    let values = &result[&cell];
+    let total = result.values().map(|values| values[1]).sum::<f64>();
+
+    // Children have (almost) the same area.
+    assert!((values[0] - 2.).abs() < 0.1, "{}", values[0]);
+    // Children slightly overflow their parent.
+    assert!(values[1] > 25. && values[1] < 30., "{}", values[1]);
+    assert!((total - 30.).abs() < 1e-6, "{total}");
+}
+
+#[test]
+fn missing_values() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+    let features = [cell_feature(cell, vec![f64::NAN])];
+
+    let result = areal_interpolation(
+        &features,
+        &[Attribute::Extensive, Attribute::Intensive],
+        Resolution::Ten,
+    );
+    let values = &result[&cell];
+
+    assert_eq!(values[0], 0.);
+    assert!(values[1].is_nan());
+}
+
+#[test]
+fn from_geojson() {
+    let json = r#"{
+      "type": "Feature",
+      "properties": { "population": 1200, "name": "foo" },
+      "geometry": {
+        "type": "Polygon",
+        "coordinates": [[
+          [37.58, 55.72], [37.66, 55.72], [37.66, 55.77], [37.58, 55.72]
+        ]]
+      }
+    }"#;
+    let feature = json.parse::<geojson::Feature>().expect("feature");
+    let feature = Feature::from_geojson(&feature, &["population", "name"])
+        .expect("polygonal feature");
+
+    let result = areal_interpolation(
+        &[feature],
+        &[Attribute::Extensive, Attribute::Intensive],
+        Resolution::Eight,
+    );
+    let total = result.values().map(|values| values[0]).sum::<f64>();
+
+    assert!((total - 1200.).abs() < 1e-6);
+    assert!(result.values().all(|values| values[1].is_nan()));
+}
+
+#[test]
+fn from_geojson_invalid() {
+    let json = r#"{
+      "type": "Feature",
+      "properties": {},
+      "geometry": { "type": "Point", "coordinates": [37.58, 55.72] }
+    }"#;
+    let feature = json.parse::<geojson::Feature>().expect("feature");
+
+    assert!(Feature::from_geojson(&feature, &[]).is_err());
+}
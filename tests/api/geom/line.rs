@@ -1,6 +1,6 @@
 use h3o::{
     geom::{Line, ToCells},
-    Resolution,
+    CellIndex, LatLng, Resolution,
 };
 
 fn line_rads() -> geo::Line {
@@ -58,3 +58,37 @@ fn to_cells() {
 
     assert!(result <= bound);
 }
+
+#[test]
+fn split() {
+    let geom = Line::from_degrees(line_degs()).expect("geom");
+    let line = line_degs();
+    let start = LatLng::new(line.start.y, line.start.x).expect("start");
+    let end = LatLng::new(line.end.y, line.end.x).expect("end");
+    let result = geom.split(Resolution::Six);
+    let length = result.iter().map(|(_, _, length)| length).sum::<f64>();
+
+    assert_eq!(result[0].0, start.to_cell(Resolution::Six));
+    assert_eq!(result[result.len() - 1].0, end.to_cell(Resolution::Six));
+    assert!(result.windows(2).all(|pair| {
+        pair[0].0.is_neighbor_with(pair[1].0) == Ok(true)
+            && pair[0].1 .0.last() == pair[1].1 .0.first()
+    }));
+    assert!((length - start.distance_m(end)).abs() < 1e-3);
+}
+
+#[test]
+fn split_same_cell() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+    let center = LatLng::from(cell);
+    let line = geo::Line::new(
+        geo::coord! { x: center.lng_radians(), y: center.lat_radians() },
+        geo::coord! { x: center.lng_radians() + 1e-6, y: center.lat_radians() },
+    );
+    let geom = Line::from_radians(line).expect("geom");
+    let result = geom.split(Resolution::Ten);
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].0, cell);
+    assert_eq!(result[0].1 .0.len(), 2);
+}
@@ -1,6 +1,6 @@
 use h3o::{
     geom::{LineString, ToCells},
-    Resolution,
+    CellIndex, LatLng, Resolution,
 };
 
 fn linestring_rads() -> geo::LineString {
@@ -60,3 +60,35 @@ fn to_cells() {
 
     assert!(result <= bound);
 }
+
+#[test]
+fn split() {
+    // Zigzag inside a single cell, then out of it.
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+    let center = LatLng::from(cell);
+    let (lat, lng) = (center.lat_radians(), center.lng_radians());
+    let line = geo::LineString::new(vec![
+        geo::coord! { x: lng, y: lat },
+        geo::coord! { x: lng + 1e-6, y: lat + 1e-6 },
+        geo::coord! { x: lng + 2e-6, y: lat },
+        geo::coord! { x: lng + 1e-4, y: lat },
+    ]);
+    let geom = LineString::from_radians(&line).expect("geom");
+    let result = geom.split(Resolution::Ten);
+    let length = result.iter().map(|(_, _, length)| length).sum::<f64>();
+    let expected = line
+        .coords()
+        .map(|coord| {
+            LatLng::from_radians(coord.y, coord.x).expect("valid coordinate")
+        })
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|pair| pair[0].distance_m(pair[1]))
+        .sum::<f64>();
+
+    assert!(result.len() > 1);
+    assert_eq!(result[0].0, cell);
+    // Pieces keep the vertices of the line.
+    assert_eq!(result[0].1 .0.len(), 4);
+    assert!((length - expected).abs() < 1e-6);
+}
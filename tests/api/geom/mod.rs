@@ -1,4 +1,7 @@
+mod circle;
+mod coverer;
 mod geometrycollection;
+mod interpolation;
 mod json;
 mod line;
 mod linestring;
@@ -7,7 +10,9 @@ mod multipoint;
 mod multipolygon;
 mod point;
 mod polygon;
+mod polygon_index;
 mod rect;
+mod simplify;
 mod to_cells;
 mod to_geo;
 mod triangle;
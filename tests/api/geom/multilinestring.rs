@@ -1,7 +1,7 @@
 use geo::line_string;
 use h3o::{
     geom::{MultiLineString, ToCells},
-    Resolution,
+    LatLng, Resolution,
 };
 
 fn multilinestring_rads() -> geo::MultiLineString {
@@ -68,3 +68,20 @@ fn to_cells() {
 
     assert!(result <= bound);
 }
+
+#[test]
+fn split() {
+    let geom =
+        MultiLineString::from_degrees(multilinestring_degs()).expect("geom");
+    let result = geom.split(Resolution::Two);
+    let expected = multilinestring_degs().0[0]
+        .coords()
+        .map(|coord| LatLng::new(coord.y, coord.x).expect("valid coordinate"))
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|pair| pair[0].distance_m(pair[1]))
+        .sum::<f64>();
+    let length = result.iter().map(|(_, _, length)| length).sum::<f64>();
+
+    assert!((length - expected).abs() < 1e-3);
+}
@@ -92,3 +92,69 @@ fn to_cells() {
 
     assert!(result <= bound);
 }
+
+#[test]
+fn coverage_sorted_and_merged() {
+    let shape = multipolygon_rads();
+    let multipolygon =
+        MultiPolygon::from_radians(&shape).expect("multipolygon");
+
+    let result = multipolygon.coverage(Resolution::Seven);
+    let clipped = multipolygon.clip(Resolution::Seven);
+
+    assert!(!result.is_empty());
+    assert!(result.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert!(result
+        .iter()
+        .all(|(_, fraction)| *fraction > 0. && *fraction <= 1.));
+    assert_eq!(result.len(), clipped.len());
+}
+
+#[test]
+fn coverage_overlapping() {
+    let shape = multipolygon_rads();
+    let single = MultiPolygon::from_radians(&shape).expect("multipolygon");
+    let twice = geo::MultiPolygon::new(
+        shape.iter().chain(shape.iter()).cloned().collect(),
+    );
+    let twice = MultiPolygon::from_radians(&twice).expect("twice");
+
+    let result = twice.coverage(Resolution::Seven);
+    let expected = single.coverage(Resolution::Seven);
+
+    assert_eq!(result.len(), expected.len());
+    for ((cell, fraction), (expected_cell, expected_fraction)) in
+        result.into_iter().zip(expected)
+    {
+        assert_eq!(cell, expected_cell);
+        assert!((fraction - expected_fraction).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn to_sorted_cells() {
+    // Two overlapping squares.
+    let shape = geo::MultiPolygon::new(vec![
+        polygon![
+            (x: 2.2, y: 48.8), (x: 2.4, y: 48.8), (x: 2.4, y: 48.9),
+            (x: 2.2, y: 48.9), (x: 2.2, y: 48.8),
+        ],
+        polygon![
+            (x: 2.3, y: 48.85), (x: 2.5, y: 48.85), (x: 2.5, y: 48.95),
+            (x: 2.3, y: 48.95), (x: 2.3, y: 48.85),
+        ],
+    ]);
+    let multipolygon = MultiPolygon::from_degrees(shape).expect("multipolygon");
+    let mut expected =
+        multipolygon.to_cells(Resolution::Eight).collect::<Vec<_>>();
+    expected.sort_unstable();
+    let count = expected.len();
+    expected.dedup();
+    assert!(expected.len() < count, "overlapping polygons");
+
+    let result = multipolygon
+        .to_sorted_cells(Resolution::Eight)
+        .collect::<Vec<_>>();
+
+    assert_eq!(result, expected);
+}
@@ -1,8 +1,9 @@
 use geo::polygon;
 use h3o::{
     geom::{Polygon, ToCells},
-    Resolution,
+    CellIndex, LatLng, Resolution,
 };
+use std::collections::HashSet;
 
 fn polygon_rads() -> geo::Polygon {
     polygon!(
@@ -110,3 +111,411 @@ fn to_cells() {
 
     assert!(result <= bound);
 }
+
+fn cell_polygon(cell: CellIndex) -> geo::Polygon {
+    let ring = cell
+        .boundary()
+        .iter()
+        .map(|ll| geo::coord! { x: ll.lng_radians(), y: ll.lat_radians() })
+        .collect::<geo::LineString>();
+    geo::Polygon::new(ring, Vec::new())
+}
+
+#[test]
+fn coverage_conserves_area() {
+    let parent = CellIndex::try_from(0x851fb467fffffff).expect("cell index");
+    let shape = cell_polygon(parent);
+    let polygon = Polygon::from_radians(&shape).expect("polygon");
+
+    let area = polygon
+        .coverage(Resolution::Seven)
+        .map(|(cell, fraction)| {
+            assert!(fraction > 0. && fraction <= 1.);
+            fraction * cell.area_rads2()
+        })
+        .sum::<f64>();
+
+    assert!((area - parent.area_rads2()).abs() / parent.area_rads2() < 1e-3);
+}
+
+#[test]
+fn coverage_cell() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+    let shape = cell_polygon(cell);
+    let polygon = Polygon::from_radians(&shape).expect("polygon");
+
+    let result = polygon
+        .coverage(Resolution::Ten)
+        .filter(|(_, fraction)| *fraction > 1e-6)
+        .collect::<Vec<_>>();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].0, cell);
+    assert!((result[0].1 - 1.).abs() < 1e-6);
+}
+
+#[test]
+fn clip_small_polygon() {
+    let cell = CellIndex::try_from(0x851fb467fffffff).expect("cell index");
+    let center = LatLng::from(cell);
+    let (lat, lng) = (center.lat_radians(), center.lng_radians());
+    let shape = polygon![
+        (x: lng - 1e-4, y: lat - 1e-4),
+        (x: lng + 1e-4, y: lat - 1e-4),
+        (x: lng + 1e-4, y: lat + 1e-4),
+        (x: lng - 1e-4, y: lat + 1e-4),
+        (x: lng - 1e-4, y: lat - 1e-4),
+    ];
+    let polygon = Polygon::from_radians(&shape).expect("polygon");
+
+    let result = polygon.clip(Resolution::Five).collect::<Vec<_>>();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].0, cell);
+    assert!(result[0].1 > 0. && result[0].1 < 0.01);
+    assert_eq!(result[0].2 .0.len(), 1);
+}
+
+#[test]
+fn coverage_near_pole() {
+    // Reaches the cell containing the north pole.
+    let shape = polygon![
+        (x: 0., y: 85.), (x: 90., y: 85.), (x: 90., y: 89.9),
+        (x: 0., y: 89.9), (x: 0., y: 85.),
+    ];
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+    let pole = LatLng::new(90., 0.).expect("pole");
+    let covered_area = |resolution| {
+        polygon
+            .coverage(resolution)
+            .map(|(cell, fraction)| fraction * cell.area_rads2())
+            .sum::<f64>()
+    };
+
+    let pole_cell = pole.to_cell(Resolution::Three);
+    let fraction = polygon
+        .coverage(Resolution::Three)
+        .find_map(|(cell, fraction)| (cell == pole_cell).then_some(fraction))
+        .expect("pole cell");
+    let coarse = covered_area(Resolution::Three);
+    let fine = covered_area(Resolution::Five);
+
+    assert!(fraction > 0. && fraction < 1.);
+    assert!((coarse - fine).abs() / fine < 1e-2, "{coarse} vs {fine}");
+}
+
+#[test]
+fn clip_transmeridian_normalized() {
+    let shape = polygon![
+        (x: 170., y: -10.), (x: -170., y: -10.), (x: -170., y: 10.),
+        (x: 170., y: 10.), (x: 170., y: -10.),
+    ];
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+
+    let result = polygon.clip(Resolution::Three).collect::<Vec<_>>();
+
+    assert!(!result.is_empty());
+    assert!(result.iter().all(|(_, _, geometry)| {
+        geometry.iter().all(|polygon| {
+            polygon
+                .exterior()
+                .coords()
+                .all(|coord| coord.x.abs() <= std::f64::consts::PI + 1e-12)
+        })
+    }));
+}
+
+fn check_compacted(shape: geo::Polygon, resolution: Resolution) {
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+    let cells = polygon.to_cells(resolution).collect::<Vec<_>>();
+    let mut expected = CellIndex::compact(cells)
+        .expect("compacted")
+        .collect::<Vec<_>>();
+    expected.sort_unstable();
+
+    let mut result = polygon.to_compacted_cells(resolution);
+    result.sort_unstable();
+
+    assert_eq!(result, expected);
+}
+
+fn check_streaming(shape: geo::Polygon, resolution: Resolution) {
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+    let mut expected = polygon.to_cells(resolution).collect::<Vec<_>>();
+    expected.sort_unstable();
+
+    let mut result = polygon.to_cells_streaming(resolution).collect::<Vec<_>>();
+    result.sort_unstable();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn to_compacted_cells() {
+    let shape = polygon![
+        (x: 2.2, y: 48.8), (x: 2.4, y: 48.8), (x: 2.45, y: 48.9),
+        (x: 2.3, y: 48.95), (x: 2.2, y: 48.9), (x: 2.2, y: 48.8),
+    ];
+
+    check_compacted(shape, Resolution::Nine);
+}
+
+#[test]
+fn to_compacted_cells_hole() {
+    let shape = polygon!(
+        exterior: [
+            (x: 0., y: 40.), (x: 10., y: 40.), (x: 10., y: 50.),
+            (x: 0., y: 50.), (x: 0., y: 40.),
+        ],
+        interiors: [
+            [
+                (x: 2., y: 42.), (x: 8., y: 42.), (x: 5., y: 48.),
+                (x: 2., y: 42.),
+            ],
+        ],
+    );
+
+    check_compacted(shape, Resolution::Six);
+}
+
+#[test]
+fn to_compacted_cells_large() {
+    let shape = polygon![
+        (x: -20., y: -30.), (x: 60., y: -35.), (x: 50., y: 50.),
+        (x: 20., y: 10.), (x: -10., y: 40.), (x: -20., y: -30.),
+    ];
+
+    check_compacted(shape, Resolution::Four);
+}
+
+#[test]
+fn to_compacted_cells_transmeridian() {
+    let shape = polygon![
+        (x: 170., y: -10.), (x: -170., y: -10.), (x: -170., y: 10.),
+        (x: 170., y: 10.), (x: 170., y: -10.),
+    ];
+
+    check_compacted(shape, Resolution::Five);
+}
+
+#[test]
+fn to_compacted_cells_pentagon() {
+    // Around the pentagon 0x851c0003fffffff.
+    let shape = polygon![
+        (x: -145., y: 48.), (x: -140., y: 48.), (x: -140., y: 52.),
+        (x: -145., y: 52.), (x: -145., y: 48.),
+    ];
+
+    check_compacted(shape, Resolution::Six);
+}
+
+#[test]
+fn to_compacted_cells_bounded() {
+    // Millions of cells at the target resolution, never listed.
+    let shape = polygon![
+        (x: 2., y: 48.), (x: 3., y: 48.), (x: 3., y: 49.),
+        (x: 2., y: 49.), (x: 2., y: 48.),
+    ];
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+    let resolution = Resolution::Eleven;
+
+    let cells = polygon.to_compacted_cells(resolution);
+    let count = cells
+        .iter()
+        .map(|cell| cell.children_count(resolution))
+        .sum::<u64>();
+    let estimate = polygon.estimate_cells_count(resolution) as u64;
+    assert!(count > 1_000_000);
+    assert!(
+        count.abs_diff(estimate) * 4 <= count,
+        "{count} vs {estimate}"
+    );
+    assert!(cells.len() as u64 * 20 < count, "compacted");
+
+    let cells = cells.into_iter().collect::<HashSet<_>>();
+    let center = LatLng::new(48.5, 2.5).expect("center").to_cell(resolution);
+    assert!(Resolution::range(Resolution::Zero, resolution).any(|res| {
+        center.parent(res).is_some_and(|cell| cells.contains(&cell))
+    }));
+}
+
+#[test]
+fn to_compacted_cells_polar() {
+    let shape = polygon![
+        (x: -30., y: 80.), (x: 30., y: 80.), (x: 30., y: 88.),
+        (x: -30., y: 88.), (x: -30., y: 80.),
+    ];
+
+    check_compacted(shape, Resolution::Five);
+}
+
+#[test]
+fn to_cells_streaming() {
+    let shape = polygon![
+        (x: 2.2, y: 48.8), (x: 2.4, y: 48.8), (x: 2.45, y: 48.9),
+        (x: 2.3, y: 48.95), (x: 2.2, y: 48.9), (x: 2.2, y: 48.8),
+    ];
+
+    check_streaming(shape, Resolution::Nine);
+}
+
+#[test]
+fn to_cells_streaming_hole() {
+    let shape = polygon!(
+        exterior: [
+            (x: 0., y: 40.), (x: 10., y: 40.), (x: 10., y: 50.),
+            (x: 0., y: 50.), (x: 0., y: 40.),
+        ],
+        interiors: [
+            [
+                (x: 2., y: 42.), (x: 8., y: 42.), (x: 5., y: 48.),
+                (x: 2., y: 42.),
+            ],
+        ],
+    );
+
+    check_streaming(shape, Resolution::Five);
+}
+
+#[test]
+fn to_cells_streaming_transmeridian() {
+    let shape = polygon![
+        (x: 170., y: -10.), (x: -170., y: -10.), (x: -170., y: 10.),
+        (x: 170., y: 10.), (x: 170., y: -10.),
+    ];
+
+    check_streaming(shape, Resolution::Four);
+}
+
+#[test]
+fn to_cells_streaming_pentagon() {
+    // Around the pentagon 0x851c0003fffffff.
+    let shape = polygon![
+        (x: -145., y: 48.), (x: -140., y: 48.), (x: -140., y: 52.),
+        (x: -145., y: 52.), (x: -145., y: 48.),
+    ];
+
+    check_streaming(shape, Resolution::Five);
+}
+
+fn check_count_estimates(shape: geo::Polygon, resolution: Resolution) {
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+    let count = polygon.to_cells(resolution).count();
+    let estimate = polygon.estimate_cells_count(resolution);
+    let bound = polygon.cells_count_upper_bound(resolution);
+
+    assert!(count <= bound, "{count} > {bound}");
+    assert!(
+        count.abs_diff(estimate) * 4 <= count,
+        "{estimate} too far from {count}"
+    );
+}
+
+#[test]
+fn estimate_cells_count_error() {
+    let shapes = [
+        // City.
+        polygon![
+            (x: 2.2, y: 48.8), (x: 2.4, y: 48.8), (x: 2.45, y: 48.9),
+            (x: 2.3, y: 48.95), (x: 2.2, y: 48.9), (x: 2.2, y: 48.8),
+        ],
+        // Triangle near the equator.
+        polygon![
+            (x: 10., y: -2.), (x: 14., y: -1.), (x: 11., y: 2.),
+            (x: 10., y: -2.),
+        ],
+        // Concave shape at high latitude.
+        polygon![
+            (x: 20., y: 65.), (x: 30., y: 65.), (x: 30., y: 70.),
+            (x: 25., y: 67.), (x: 20., y: 70.), (x: 20., y: 65.),
+        ],
+        // Around the pentagon 0x851c0003fffffff, where cells are smaller.
+        polygon![
+            (x: -145., y: 48.), (x: -140., y: 48.), (x: -140., y: 52.),
+            (x: -145., y: 52.), (x: -145., y: 48.),
+        ],
+    ];
+
+    for shape in shapes {
+        let polygon = Polygon::from_degrees(shape).expect("polygon");
+        // Coarsest resolution with enough cells for the outline to be
+        // negligible, and the two next ones.
+        let start = Resolution::range(Resolution::Zero, Resolution::Fifteen)
+            .find(|&resolution| polygon.estimate_cells_count(resolution) >= 500)
+            .expect("resolution");
+        for resolution in Resolution::range(start, Resolution::Fifteen).take(3)
+        {
+            let count = polygon.to_cells(resolution).count();
+            let estimate = polygon.estimate_cells_count(resolution);
+            let ratio = estimate as f64 / count as f64;
+
+            // Bounded by the extreme cell areas, relative to the average.
+            assert!(
+                (0.5..=1.21).contains(&ratio),
+                "{estimate} vs {count} at {resolution}"
+            );
+        }
+    }
+}
+
+#[test]
+fn count_estimates_diagonal() {
+    let shape = polygon![
+        (x: 0., y: 0.), (x: 10., y: 10.), (x: 10.05, y: 10.),
+        (x: 0.05, y: 0.), (x: 0., y: 0.),
+    ];
+
+    check_count_estimates(shape, Resolution::Seven);
+}
+
+#[test]
+fn count_estimates_l_shape() {
+    let shape = polygon![
+        (x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 0.2), (x: 0.2, y: 0.2),
+        (x: 0.2, y: 10.), (x: 0., y: 10.), (x: 0., y: 0.),
+    ];
+
+    check_count_estimates(shape, Resolution::Six);
+}
+
+#[test]
+fn count_estimates_hole() {
+    let shape = geo::Polygon::new(
+        geo::LineString::from(vec![
+            (0., 0.),
+            (4., 0.),
+            (4., 4.),
+            (0., 4.),
+            (0., 0.),
+        ]),
+        vec![geo::LineString::from(vec![
+            (1., 1.),
+            (3., 1.),
+            (3., 3.),
+            (1., 3.),
+            (1., 1.),
+        ])],
+    );
+
+    check_count_estimates(shape, Resolution::Six);
+}
+
+#[test]
+fn count_estimates_transmeridian() {
+    let shape = polygon![
+        (x: 179., y: -10.), (x: -179., y: -10.), (x: -179., y: 10.),
+        (x: 179., y: 10.), (x: 179., y: -10.),
+    ];
+
+    check_count_estimates(shape, Resolution::Five);
+}
+
+#[test]
+fn count_estimates_high_latitude() {
+    let shape = polygon![
+        (x: 0., y: 60.), (x: 90., y: 60.), (x: 90., y: 80.), (x: 0., y: 80.),
+        (x: 0., y: 60.),
+    ];
+
+    check_count_estimates(shape, Resolution::Four);
+}
@@ -0,0 +1,102 @@
+use geo::polygon;
+use h3o::{
+    geom::{Polygon, PolygonIndex, ToCells},
+    CellIndex, LatLng, Resolution,
+};
+use std::collections::HashSet;
+
+/// Checks the lookups against the polyfill, on the center of every cell around
+/// the given point.
+fn check(
+    shapes: Vec<geo::Polygon>,
+    resolution: Resolution,
+    fine: Resolution,
+    origin: LatLng,
+    k: u32,
+) {
+    let mut index = PolygonIndex::new(resolution);
+    let mut coverages = Vec::new();
+    for (id, shape) in shapes.into_iter().enumerate() {
+        let polygon = Polygon::from_degrees(shape).expect("polygon");
+        coverages.push(polygon.to_cells(fine).collect::<HashSet<_>>());
+        index.insert(id, polygon);
+    }
+    assert_eq!(index.len(), coverages.len());
+
+    let mut hits = 0;
+    for cell in origin.to_cell(fine).grid_disk::<Vec<CellIndex>>(k) {
+        let expected = coverages
+            .iter()
+            .enumerate()
+            .filter_map(|(id, cells)| cells.contains(&cell).then_some(id))
+            .collect::<Vec<_>>();
+        let result = index
+            .lookup(LatLng::from(cell))
+            .into_iter()
+            .copied()
+            .collect::<Vec<_>>();
+
+        assert_eq!(result, expected, "{cell}");
+        hits += usize::from(!result.is_empty());
+    }
+    assert!(hits > 0);
+}
+
+#[test]
+fn lookup() {
+    let square = polygon![
+        (x: 2.2, y: 48.8), (x: 2.4, y: 48.8), (x: 2.4, y: 48.9),
+        (x: 2.2, y: 48.9), (x: 2.2, y: 48.8),
+    ];
+    let triangle = polygon![
+        (x: 2.3, y: 48.85), (x: 2.5, y: 48.82), (x: 2.4, y: 48.95),
+        (x: 2.3, y: 48.85),
+    ];
+    let donut = polygon!(
+        exterior: [
+            (x: 2.15, y: 48.75), (x: 2.3, y: 48.75), (x: 2.3, y: 48.83),
+            (x: 2.15, y: 48.83), (x: 2.15, y: 48.75),
+        ],
+        interiors: [
+            [
+                (x: 2.2, y: 48.77), (x: 2.25, y: 48.77), (x: 2.25, y: 48.8),
+                (x: 2.2, y: 48.8), (x: 2.2, y: 48.77),
+            ],
+        ],
+    );
+    let origin = LatLng::new(48.85, 2.3).expect("origin");
+
+    check(
+        vec![square, triangle, donut],
+        Resolution::Six,
+        Resolution::Nine,
+        origin,
+        80,
+    );
+}
+
+#[test]
+fn lookup_transmeridian() {
+    let shape = polygon![
+        (x: 175., y: -5.), (x: -175., y: -5.), (x: -175., y: 5.),
+        (x: 175., y: 5.), (x: 175., y: -5.),
+    ];
+    let origin = LatLng::new(0., 180.).expect("origin");
+
+    check(
+        vec![shape],
+        Resolution::Three,
+        Resolution::Five,
+        origin,
+        100,
+    );
+}
+
+#[test]
+fn lookup_empty() {
+    let index = PolygonIndex::<u32>::new(Resolution::Seven);
+    let point = LatLng::new(48.85, 2.3).expect("point");
+
+    assert!(index.is_empty());
+    assert!(index.lookup(point).is_empty());
+}
@@ -1,6 +1,8 @@
+use ahash::HashSet;
+use geo::polygon;
 use h3o::{
-    geom::{Rect, ToCells},
-    Resolution,
+    geom::{Polygon, Rect, ToCells},
+    LatLng, Resolution,
 };
 
 fn rect_rads() -> geo::Rect {
@@ -58,3 +60,183 @@ fn to_cells() {
 
     assert!(result <= bound);
 }
+
+#[test]
+fn try_to_cells() {
+    let geom = Rect::from_degrees(rect_degs()).expect("geom");
+    let bound = geom.max_cells_count(Resolution::Two);
+    let result = geom
+        .try_to_cells(Resolution::Two, bound)
+        .expect("within limit")
+        .collect::<Vec<_>>();
+    let expected = geom.to_cells(Resolution::Two).collect::<Vec<_>>();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn try_to_cells_too_many() {
+    let rect = geo::Rect::new(
+        geo::coord! { x: 0., y: 0. },
+        geo::coord! { x: 90., y: 60. },
+    );
+    let geom = Rect::from_degrees(rect).expect("geom");
+    let err = geom
+        .try_to_cells(Resolution::Fifteen, 1_000_000)
+        .err()
+        .expect("too many cells");
+
+    assert_eq!(err.count(), geom.max_cells_count(Resolution::Fifteen));
+    assert_eq!(err.limit(), 1_000_000);
+}
+
+#[test]
+fn transmeridian() {
+    let geom = Rect::from_corners_degrees(
+        geo::coord! { x: 170., y: -10. },
+        geo::coord! { x: -170., y: 10. },
+    )
+    .expect("geom");
+    let shape = polygon![
+        (x: 170., y: -10.), (x: -170., y: -10.), (x: -170., y: 10.),
+        (x: 170., y: 10.), (x: 170., y: -10.),
+    ];
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+
+    let mut result = geom.to_cells(Resolution::Four).collect::<Vec<_>>();
+    result.sort_unstable();
+    let mut expected = polygon.to_cells(Resolution::Four).collect::<Vec<_>>();
+    expected.sort_unstable();
+
+    assert_eq!(result, expected);
+    assert!(result.len() <= geom.max_cells_count(Resolution::Four));
+    assert!(result.len() <= geom.cells_count_upper_bound(Resolution::Four));
+}
+
+#[test]
+fn transmeridian_wide() {
+    // Everything but the band between 10°W and 10°E.
+    let geom = Rect::from_corners_degrees(
+        geo::coord! { x: 10., y: -10. },
+        geo::coord! { x: -10., y: 10. },
+    )
+    .expect("geom");
+    let cells = geom.to_cells(Resolution::Two).collect::<HashSet<_>>();
+
+    for (lng, expected) in
+        [(0., false), (90., true), (180., true), (-90., true)]
+    {
+        let cell = LatLng::new(0., lng)
+            .expect("coordinate")
+            .to_cell(Resolution::Two);
+        assert_eq!(cells.contains(&cell), expected, "{lng}");
+    }
+    assert!(cells.len() <= geom.max_cells_count(Resolution::Two));
+}
+
+#[test]
+fn wide() {
+    // Not crossing the antimeridian, but larger than half the globe.
+    let geom = Rect::from_degrees(geo::Rect::new(
+        geo::coord! { x: -170., y: -10. },
+        geo::coord! { x: 170., y: 10. },
+    ))
+    .expect("geom");
+    let cells = geom.to_cells(Resolution::Two).collect::<HashSet<_>>();
+
+    for (lng, expected) in [(0., true), (90., true), (180., false)] {
+        let cell = LatLng::new(0., lng)
+            .expect("coordinate")
+            .to_cell(Resolution::Two);
+        assert_eq!(cells.contains(&cell), expected, "{lng}");
+    }
+}
+
+#[test]
+fn full_width() {
+    let geom = Rect::from_degrees(geo::Rect::new(
+        geo::coord! { x: -180., y: -10. },
+        geo::coord! { x: 180., y: 10. },
+    ))
+    .expect("geom");
+    let cells = geom.to_cells(Resolution::Two).collect::<HashSet<_>>();
+
+    for lng in [0., 45., 90., 135., 180., -135., -90., -45.] {
+        let cell = LatLng::new(0., lng)
+            .expect("coordinate")
+            .to_cell(Resolution::Two);
+        assert!(cells.contains(&cell), "{lng}");
+    }
+}
+
+#[test]
+fn wider_than_full_turn() {
+    let result = Rect::from_corners_degrees(
+        geo::coord! { x: -200., y: -10. },
+        geo::coord! { x: 200., y: 10. },
+    );
+    assert!(result.is_err());
+
+    let result = Rect::from_corners_degrees(
+        geo::coord! { x: 10., y: -10. },
+        geo::coord! { x: 0., y: 10. },
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn into_geo_transmeridian() {
+    let geom = Rect::from_corners_degrees(
+        geo::coord! { x: 170., y: -10. },
+        geo::coord! { x: -170., y: 10. },
+    )
+    .expect("geom");
+    let result = geo::Rect::from(geom);
+
+    assert!((result.min().x - 170_f64.to_radians()).abs() < 1e-12);
+    assert!((result.max().x - 190_f64.to_radians()).abs() < 1e-12);
+}
+
+#[test]
+fn unnormalized_longitudes() {
+    let geom = Rect::from_corners_degrees(
+        geo::coord! { x: 190., y: -10. },
+        geo::coord! { x: -160., y: 10. },
+    )
+    .expect("geom");
+    let normalized = Rect::from_corners_degrees(
+        geo::coord! { x: -170., y: -10. },
+        geo::coord! { x: -160., y: 10. },
+    )
+    .expect("normalized");
+
+    let mut result = geom.to_cells(Resolution::Four).collect::<Vec<_>>();
+    result.sort_unstable();
+    let mut expected =
+        normalized.to_cells(Resolution::Four).collect::<Vec<_>>();
+    expected.sort_unstable();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn from_degrees_shifted_east() {
+    let rect = geo::Rect::new(
+        geo::coord! { x: 170., y: -10. },
+        geo::coord! { x: 190., y: 10. },
+    );
+    let geom = Rect::from_degrees(rect).expect("geom");
+    let transmeridian = Rect::from_corners_degrees(
+        geo::coord! { x: 170., y: -10. },
+        geo::coord! { x: -170., y: 10. },
+    )
+    .expect("transmeridian");
+
+    let mut result = geom.to_cells(Resolution::Four).collect::<Vec<_>>();
+    result.sort_unstable();
+    let mut expected =
+        transmeridian.to_cells(Resolution::Four).collect::<Vec<_>>();
+    expected.sort_unstable();
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,129 @@
+use geo::{
+    line_intersection::{line_intersection, LineIntersection},
+    Contains, Line, LineString, MultiPolygon, Polygon,
+};
+use h3o::{
+    geom::{simplify_outlines, ToGeo},
+    CellIndex,
+};
+
+fn rings(region: &MultiPolygon<f64>) -> Vec<LineString<f64>> {
+    region
+        .iter()
+        .flat_map(|polygon| {
+            std::iter::once(polygon.exterior().clone())
+                .chain(polygon.interiors().iter().cloned())
+        })
+        .collect()
+}
+
+fn assert_no_crossing(rings: &[LineString<f64>]) {
+    let lines = rings.iter().flat_map(LineString::lines).collect::<Vec<_>>();
+    for (i, a) in lines.iter().enumerate() {
+        for b in &lines[i + 1..] {
+            match line_intersection(*a, *b) {
+                None => (),
+                Some(LineIntersection::SinglePoint {
+                    intersection,
+                    is_proper,
+                }) => {
+                    assert!(!is_proper, "{a:?} crosses {b:?}");
+                    assert!([a.start, a.end].contains(&intersection));
+                    assert!([b.start, b.end].contains(&intersection));
+                }
+                Some(LineIntersection::Collinear { .. }) => {
+                    assert_eq!(*a, Line::new(b.end, b.start));
+                }
+            }
+        }
+    }
+}
+
+/// A disk with a hole, surrounded by a ring.
+fn regions() -> [MultiPolygon<f64>; 2] {
+    let center = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+    let disk = center
+        .grid_disk::<Vec<_>>(30)
+        .into_iter()
+        .filter(|cell| center.grid_distance(*cell).expect("distance") > 2)
+        .collect::<Vec<_>>();
+    let ring = (31..35)
+        .flat_map(|k| {
+            center
+                .grid_ring_fast(k)
+                .collect::<Option<Vec<_>>>()
+                .expect("ring")
+        })
+        .collect::<Vec<_>>();
+
+    [
+        disk.to_geom(true).expect("disk"),
+        ring.to_geom(true).expect("ring"),
+    ]
+}
+
+#[test]
+fn simplify() {
+    let regions = regions();
+    let before = regions.iter().flat_map(rings).collect::<Vec<_>>();
+
+    let result = simplify_outlines(&regions, 1e-3);
+    let after = result.iter().flat_map(rings).collect::<Vec<_>>();
+
+    assert_eq!(after.len(), before.len());
+    let count = |rings: &[LineString<f64>]| {
+        rings.iter().map(|ring| ring.0.len()).sum::<usize>()
+    };
+    assert!(count(&after) < count(&before) / 10);
+    for ring in &after {
+        assert!(ring.is_closed());
+        assert!(ring.0.len() >= 4);
+    }
+    assert_no_crossing(&after);
+}
+
+#[test]
+fn shared_borders() {
+    let regions = regions();
+
+    let result = simplify_outlines(&regions, 1e-3);
+
+    // The outer ring of the disk is the inner ring of the ring.
+    let mut outer = result[0].0[0].exterior().0.clone();
+    let mut inner = result[1].0[0].interiors()[0].0.clone();
+    outer.pop();
+    inner.pop();
+    let start = inner
+        .iter()
+        .position(|coord| *coord == outer[0])
+        .expect("shared vertex");
+    inner.rotate_left(start);
+    inner[1..].reverse();
+    assert_eq!(outer, inner);
+}
+
+#[test]
+fn holes_stay_inside() {
+    let regions = regions();
+
+    // Huge tolerance, every ring is reduced to its minimum.
+    let result = simplify_outlines(&regions, 10.);
+
+    for ring in result.iter().flat_map(rings) {
+        assert!(ring.0.len() >= 4);
+    }
+    assert_no_crossing(&result.iter().flat_map(rings).collect::<Vec<_>>());
+    for polygon in result.iter().flat_map(|region| region.iter()) {
+        let shell = Polygon::new(polygon.exterior().clone(), Vec::new());
+        for hole in polygon.interiors() {
+            assert!(hole.0.iter().all(|coord| shell.contains(coord)));
+        }
+    }
+}
+
+#[test]
+fn empty() {
+    assert!(simplify_outlines(&[], 1e-3).is_empty());
+    let result = simplify_outlines(&[MultiPolygon::new(Vec::new())], 1e-3);
+    assert_eq!(result, vec![MultiPolygon::new(Vec::new())]);
+}
@@ -1,5 +1,10 @@
 use approx::assert_relative_eq;
-use h3o::{geom::ToGeo, CellIndex, DirectedEdgeIndex, Resolution, VertexIndex};
+use h3o::{
+    error::OutlinerError,
+    geom::{EdgeSet, ToGeo},
+    routing::Router,
+    CellIndex, DirectedEdgeIndex, Resolution, VertexIndex,
+};
 
 #[test]
 fn from_cells() {
@@ -165,14 +170,84 @@ fn consecutive_duplicate() {
 }
 
 #[test]
-fn resolution_mismatch() {
+fn overlap() {
+    // A cell and its parent.
     let set = [0x89283082813ffff, 0x8828308281fffff]
         .into_iter()
         .map(|bits| CellIndex::try_from(bits).expect("cell index"));
     let result = set.to_geom(false);
 
     // No shape.
-    assert!(result.is_err())
+    assert_eq!(result, Err(OutlinerError::HeterogeneousResolution));
+}
+
+#[test]
+fn compacted() {
+    let cell = CellIndex::try_from(0x85283473fffffff).expect("cell index");
+    let mut cells = cell
+        .grid_disk::<Vec<_>>(1)
+        .into_iter()
+        .flat_map(|cell| cell.children(Resolution::Eight))
+        .filter(|&child| {
+            child != cell.center_child(Resolution::Eight).expect("center")
+        })
+        .collect::<Vec<_>>();
+    cells.sort_unstable();
+    let compacted = CellIndex::compact(cells.clone())
+        .expect("compacted")
+        .collect::<Vec<_>>();
+
+    let result = compacted.to_geom(true).expect("geometry");
+    let expected = cells.to_geom(true).expect("geometry");
+
+    assert_eq!(result, expected);
+    // 1 polygon, with a hole.
+    assert_eq!(result.0.len(), 1);
+    assert_eq!(result.0[0].interiors().len(), 1);
+}
+
+#[test]
+fn compacted_pentagon() {
+    let pentagon = Resolution::Two.pentagons().next().expect("pentagon");
+    let mut cells = pentagon.grid_disk::<Vec<_>>(1);
+    let neighbor = pentagon
+        .grid_disk_safe(2)
+        .find(|cell| !cells.contains(cell))
+        .expect("neighbor");
+    cells.push(neighbor.center_child(Resolution::Five).expect("center"));
+
+    let result = cells.clone().to_geom(true).expect("geometry");
+    let expected = CellIndex::uncompact(cells, Resolution::Five)
+        .to_geom(true)
+        .expect("geometry");
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn compacted_resolution_gaps() {
+    let hexagon = CellIndex::try_from(0x822837fffffffff).expect("hexagon");
+    let pentagon = Resolution::Two.pentagons().next().expect("pentagon");
+
+    for cell in [hexagon, pentagon] {
+        let disk = cell.grid_disk::<Vec<_>>(1);
+        let neighbor = cell
+            .grid_disk_safe(2)
+            .find(|cell| !disk.contains(cell))
+            .expect("neighbor");
+        for gap in 1..=4 {
+            let resolution = Resolution::try_from(2 + gap).expect("resolution");
+            let mut cells = disk.clone();
+            cells.push(neighbor.center_child(resolution).expect("center"));
+
+            let result = cells.clone().to_geom(true).expect("geometry");
+            let expected = CellIndex::uncompact(cells, resolution)
+                .to_geom(true)
+                .expect("geometry");
+
+            assert_eq!(result, expected, "{cell} at resolution {resolution}");
+        }
+    }
 }
 
 #[test]
@@ -495,3 +570,92 @@ grid_disk!(grid_disk_pentagon_res12, 0x8031fffffffffff, 12);
 grid_disk!(grid_disk_pentagon_res13, 0x8031fffffffffff, 13);
 grid_disk!(grid_disk_pentagon_res14, 0x8031fffffffffff, 14);
 grid_disk!(grid_disk_pentagon_res15, 0x8031fffffffffff, 15);
+
+#[test]
+fn from_reachability() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let mut router = Router::new(|_| 1.).max_cost(2.);
+    let reachability = router.reachable([src]).expect("reachability");
+
+    let result = reachability.to_geom(true).expect("geometry");
+    let expected = src.grid_disk::<Vec<_>>(2).to_geom(true).expect("disk");
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn from_component() {
+    let cell1 = CellIndex::try_from(0x8a1fb46622dffff).expect("cell1");
+    let cell2 = cell1.grid_ring_fast(5).next().flatten().expect("cell2");
+    let mut cells = cell1.grid_disk::<Vec<_>>(2);
+    cells.extend(cell2.grid_disk::<Vec<_>>(1));
+    let components = h3o::cell_set::components(cells).expect("components");
+
+    for id in 0..components.count() {
+        let result = components.component(id).to_geom(true).expect("outline");
+        assert_eq!(result.0.len(), 1);
+    }
+}
+
+#[test]
+fn from_edge_set_cell() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+    let result = EdgeSet::new(cell.edges()).to_geom(true).expect("geometry");
+
+    // A single closed loop.
+    assert_eq!(result.0.len(), 1);
+    assert!(result.0[0].is_closed());
+    assert_eq!(result.0[0].0.len(), 7);
+}
+
+#[test]
+fn from_edge_set_region() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+    let disk = cell.grid_disk::<Vec<_>>(1);
+    let edges = disk
+        .iter()
+        .flat_map(|cell| cell.edges())
+        .filter(|edge| !disk.contains(&edge.destination()))
+        .collect::<EdgeSet>();
+
+    let result = edges.to_geom(true).expect("geometry");
+    let expected = disk.to_geom(true).expect("outline");
+
+    // A single closed loop, matching the outline of the region.
+    assert_eq!(result.0.len(), 1);
+    assert!(result.0[0].is_closed());
+    assert_eq!(result.0[0].0.len(), expected.0[0].exterior().0.len());
+}
+
+#[test]
+fn from_edge_set_branching() {
+    let cell1 = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+    let cell2 = cell1.grid_ring_fast(1).next().flatten().expect("neighbor");
+    let edges = cell1.edges().chain(cell2.edges()).collect::<EdgeSet>();
+
+    let result = edges.to_geom(false).expect("geometry");
+
+    // Both directions of the shared edge, and the rest of each cell.
+    assert_eq!(result.0.len(), 4);
+    let lengths = result.0.iter().map(|line| line.0.len()).collect::<Vec<_>>();
+    assert_eq!(lengths.iter().filter(|&&len| len == 2).count(), 2);
+    assert_eq!(lengths.iter().filter(|&&len| len == 6).count(), 2);
+}
+
+#[test]
+fn from_edge_set_open() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+    let edges = cell.edges().skip(1).collect::<EdgeSet>();
+
+    let result = edges.to_geom(true).expect("geometry");
+
+    // A single open line, in the direction of the edges.
+    assert_eq!(result.0.len(), 1);
+    assert!(!result.0[0].is_closed());
+    assert_eq!(result.0[0].0.len(), 6);
+    assert!(EdgeSet::default()
+        .to_geom(true)
+        .expect("empty")
+        .0
+        .is_empty());
+}
@@ -1,5 +1,5 @@
 use float_eq::assert_float_eq;
-use h3o::{CellIndex, LatLng, Resolution};
+use h3o::{CellIndex, DirectedEdgeIndex, LatLng, Resolution};
 use std::f64::consts::{FRAC_PI_2, PI};
 
 const EPSILON: f64 = 1e-9 * PI / 180.0;
@@ -103,3 +103,108 @@ fn to_cell_icosahedron_center() {
 
     assert_eq!(result, expected);
 }
+
+fn assert_edge_path(
+    src: LatLng,
+    dst: LatLng,
+    resolution: Resolution,
+    edges: &[DirectedEdgeIndex],
+) {
+    if let (Some(first), Some(last)) = (edges.first(), edges.last()) {
+        assert_eq!(first.origin(), src.to_cell(resolution), "first edge");
+        assert_eq!(last.destination(), dst.to_cell(resolution), "last edge");
+    } else {
+        assert_eq!(src.to_cell(resolution), dst.to_cell(resolution));
+    }
+    assert!(
+        edges
+            .windows(2)
+            .all(|pair| pair[0].destination() == pair[1].origin()),
+        "contiguous edges"
+    );
+}
+
+#[test]
+fn crossed_edges() {
+    let src = LatLng::new(48.864716, 2.349014).expect("src");
+    let dst = LatLng::new(48.858093, 2.294694).expect("dst");
+    let result = src.crossed_edges(dst, Resolution::Nine);
+
+    assert!(!result.is_empty());
+    assert_edge_path(src, dst, Resolution::Nine, &result);
+}
+
+#[test]
+fn crossed_edges_same_cell() {
+    let src = LatLng::new(48.864716, 2.349014).expect("src");
+    let result = src.crossed_edges(src, Resolution::Nine);
+
+    assert!(result.is_empty());
+}
+
+#[test]
+fn crossed_edges_pentagon() {
+    let pentagon = CellIndex::try_from(0x8a0800000007fff).expect("pentagon");
+    let center = LatLng::from(pentagon);
+    let resolution = pentagon.resolution();
+    // Cross the pentagon, from one neighbor to the other side.
+    for neighbor in pentagon.grid_disk::<Vec<_>>(1) {
+        let src = LatLng::from(neighbor);
+        let dst = LatLng::from_radians(
+            2.0_f64.mul_add(center.lat_radians(), -src.lat_radians()),
+            2.0_f64.mul_add(center.lng_radians(), -src.lng_radians()),
+        )
+        .expect("dst");
+        let result = src.crossed_edges(dst, resolution);
+
+        assert_edge_path(src, dst, resolution, &result);
+    }
+}
+
+#[test]
+fn crossed_edges_through_vertex() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let resolution = cell.resolution();
+    let src = LatLng::from(cell);
+    for vertex in cell.boundary().iter() {
+        let dst = LatLng::from_radians(
+            2.0_f64.mul_add(vertex.lat_radians(), -src.lat_radians()),
+            2.0_f64.mul_add(vertex.lng_radians(), -src.lng_radians()),
+        )
+        .expect("dst");
+        let result = src.crossed_edges(dst, resolution);
+
+        assert_edge_path(src, dst, resolution, &result);
+        assert_eq!(result, src.crossed_edges(dst, resolution), "stable");
+    }
+}
+
+#[test]
+fn crossed_edges_antipodal() {
+    let src = LatLng::new(10., 20.).expect("src");
+    let dst = LatLng::new(-10., -160.).expect("dst");
+    // The walk must follow the arc (the grid fallback is too slow).
+    let result = src.crossed_edges(dst, Resolution::Nine);
+
+    assert_eq!(
+        result.last().map(|edge| edge.destination()),
+        Some(dst.to_cell(Resolution::Nine))
+    );
+    assert_edge_path(src, dst, Resolution::Nine, &result);
+}
+
+#[test]
+fn crossed_edges_ends_at_destination() {
+    // Vertex to vertex at res 15, where the arc can't be followed exactly.
+    let src = LatLng::from_radians(1.095806315903715, 2.717350090740481)
+        .expect("src");
+    let dst = LatLng::from_radians(1.0958063104186593, 2.7173506611286085)
+        .expect("dst");
+    let result = src.crossed_edges(dst, Resolution::Fifteen);
+
+    assert_eq!(
+        result.last().map(|edge| edge.destination()),
+        Some(dst.to_cell(Resolution::Fifteen))
+    );
+    assert_edge_path(src, dst, Resolution::Fifteen, &result);
+}
@@ -1,6 +1,7 @@
 mod base_cell;
 mod boundary;
 mod cell_index;
+mod cell_set;
 mod directed_edge_index;
 mod direction;
 mod edge;
@@ -11,7 +12,10 @@ mod geom;
 mod index_mode;
 mod latlng;
 mod localij;
+mod point_index;
 mod resolution;
+mod routing;
+mod trajectory;
 mod vertex;
 mod vertex_index;
 
@@ -0,0 +1,112 @@
+use h3o::{point_index::PointIndex, CellIndex, LatLng, Resolution};
+
+/// Pseudo-random points around a location (deterministic xorshift).
+fn points_around(center: LatLng, spread: f64, count: usize) -> Vec<LatLng> {
+    let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+    let mut random = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed >> 11) as f64 / (1_u64 << 53) as f64 - 0.5
+    };
+    (0..count)
+        .map(|_| {
+            LatLng::new(
+                center.lat() + random() * spread,
+                center.lng() + random() * spread,
+            )
+            .expect("valid point")
+        })
+        .collect()
+}
+
+fn build_index(points: &[LatLng], resolution: Resolution) -> PointIndex<usize> {
+    let mut index = PointIndex::new(resolution);
+    for (i, &point) in points.iter().enumerate() {
+        index.insert(point, i);
+    }
+    index
+}
+
+fn brute_force(points: &[LatLng], location: LatLng) -> Vec<(usize, f64)> {
+    let mut distances = points
+        .iter()
+        .enumerate()
+        .map(|(i, point)| (i, point.distance_m(location)))
+        .collect::<Vec<_>>();
+    distances.sort_by(|a, b| a.1.total_cmp(&b.1));
+    distances
+}
+
+fn check_queries(points: &[LatLng], resolution: Resolution, location: LatLng) {
+    let index = build_index(points, resolution);
+    let expected = brute_force(points, location);
+
+    let nearest = index.nearest(location, 10);
+    assert_eq!(nearest.len(), 10);
+    for (result, expected) in nearest.iter().zip(&expected) {
+        assert_eq!(result.2, expected.1, "nearest distance");
+    }
+
+    let radius = expected[25].1;
+    let within = index.within(location, radius);
+    assert_eq!(within.len(), 26, "within count");
+    for (result, expected) in within.iter().zip(&expected) {
+        assert_eq!(result.2, expected.1, "within distance");
+    }
+}
+
+#[test]
+fn queries() {
+    let center = LatLng::new(48.864716, 2.349014).expect("center");
+    let points = points_around(center, 0.2, 500);
+
+    check_queries(&points, Resolution::Eight, center);
+    check_queries(&points, Resolution::Five, center);
+}
+
+#[test]
+fn queries_pentagon() {
+    let pentagon = CellIndex::try_from(0x85080003fffffff).expect("pentagon");
+    let center = LatLng::from(pentagon);
+    let points = points_around(center, 0.5, 500);
+    assert!(pentagon.is_pentagon());
+
+    check_queries(&points, Resolution::Six, center);
+    check_queries(&points, Resolution::Seven, center);
+}
+
+#[test]
+fn nearest_sparse() {
+    // Few points, far apart: the search must go past many empty cells.
+    let center = LatLng::new(48.864716, 2.349014).expect("center");
+    let points = [
+        LatLng::new(48.9, 2.4).expect("point"),
+        LatLng::new(47., 3.).expect("point"),
+    ];
+    let index = build_index(&points, Resolution::Seven);
+
+    let result = index.nearest(center, 5);
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(*result[0].1, 0);
+    assert_eq!(*result[1].1, 1);
+}
+
+#[test]
+fn insert_remove() {
+    let point = LatLng::new(48.864716, 2.349014).expect("point");
+    let mut index = PointIndex::new(Resolution::Nine);
+    index.insert(point, "a");
+    index.insert(point, "b");
+
+    assert_eq!(index.len(), 2);
+    assert_eq!(index.remove(point, &"c"), None);
+    assert_eq!(index.remove(point, &"a"), Some("a"));
+    assert_eq!(index.len(), 1);
+    assert_eq!(index.nearest(point, 2).len(), 1);
+    assert_eq!(index.remove(point, &"b"), Some("b"));
+    assert!(index.is_empty());
+    assert!(index.nearest(point, 1).is_empty());
+    assert!(index.within(point, 100.).is_empty());
+}
@@ -0,0 +1,195 @@
+use h3o::{
+    error::ResolutionMismatch, routing::Router, CellIndex, DirectedEdgeIndex,
+    LatLng, Resolution,
+};
+
+fn center_distance(edge: DirectedEdgeIndex) -> f64 {
+    let (src, dst) = edge.cells();
+    LatLng::from(src).distance_rads(dst.into())
+}
+
+#[test]
+fn dijkstra_uniform_cost() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let dst = CellIndex::try_from(0x8a1fb4644937fff).expect("dst");
+    let mut router = Router::new(|_| 1.);
+
+    let route = router.dijkstra(src, dst).expect("same res").expect("route");
+    let distance = src.grid_distance(dst).expect("distance");
+
+    assert_eq!(route.cost(), f64::from(distance));
+    assert_eq!(route.cells().len(), route.edges().len() + 1);
+    assert_eq!(route.cells().first(), Some(&src));
+    assert_eq!(route.cells().last(), Some(&dst));
+    for (edge, cells) in route.edges().iter().zip(route.cells().windows(2)) {
+        assert_eq!(edge.cells(), (cells[0], cells[1]));
+    }
+}
+
+#[test]
+fn astar_matches_dijkstra() {
+    let src = CellIndex::try_from(0x85283473fffffff).expect("src");
+    let dst = CellIndex::try_from(0x8528342bfffffff).expect("dst");
+    let mut router = Router::new(center_distance);
+
+    let expected = router.dijkstra(src, dst).expect("same res").expect("route");
+    let result = router
+        .astar(src, dst, 1.)
+        .expect("same res")
+        .expect("route");
+
+    assert!((result.cost() - expected.cost()).abs() < 1e-12);
+}
+
+#[test]
+fn across_pentagon() {
+    let src = CellIndex::try_from(0x81083ffffffffff).expect("src");
+    let dst = CellIndex::try_from(0x810a3ffffffffff).expect("dst");
+    let mut router = Router::new(|_| 1.);
+
+    let route = router.dijkstra(src, dst).expect("same res").expect("route");
+
+    assert_eq!(route.cost(), 5.);
+}
+
+#[test]
+fn same_cell() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let mut router = Router::new(|_| 1.);
+
+    let route = router
+        .dijkstra(cell, cell)
+        .expect("same res")
+        .expect("route");
+
+    assert_eq!(route.cells(), &[cell]);
+    assert!(route.edges().is_empty());
+    assert_eq!(route.cost(), 0.);
+}
+
+#[test]
+fn blocked() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let dst = CellIndex::try_from(0x8a1fb4644937fff).expect("dst");
+    let path = src
+        .grid_path_cells(dst)
+        .expect("path")
+        .collect::<Result<Vec<_>, _>>()
+        .expect("cells");
+    let wall = path[path.len() / 2];
+    let mut router = Router::new(|_| 1.).blocked([wall]);
+
+    let route = router.dijkstra(src, dst).expect("same res").expect("route");
+    assert!(!route.cells().contains(&wall));
+
+    let mut router =
+        Router::new(|_| 1.).blocked(src.grid_ring_fast(1).flatten());
+    let route = router.dijkstra(src, dst).expect("same res");
+    assert!(route.is_none());
+}
+
+#[test]
+fn impassable_edges() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let dst = CellIndex::try_from(0x8a1fb4644937fff).expect("dst");
+    let mut router = Router::new(|edge: DirectedEdgeIndex| {
+        if edge.origin() == src {
+            f64::INFINITY
+        } else {
+            1.
+        }
+    });
+
+    let route = router.dijkstra(src, dst).expect("same res");
+
+    assert!(route.is_none());
+}
+
+#[test]
+fn max_radius() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let dst = CellIndex::try_from(0x8a1fb4644937fff).expect("dst");
+    let distance = src.grid_distance(dst).expect("distance").unsigned_abs();
+
+    let mut router = Router::new(|_| 1.).max_radius(distance - 1);
+    assert!(router.dijkstra(src, dst).expect("same res").is_none());
+
+    let mut router = Router::new(|_| 1.).max_radius(distance);
+    assert!(router.dijkstra(src, dst).expect("same res").is_some());
+}
+
+#[test]
+fn resolution_mismatch() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let dst = src.parent(Resolution::Five).expect("parent");
+    let mut router = Router::new(|_| 1.);
+
+    assert_eq!(router.dijkstra(src, dst), Err(ResolutionMismatch));
+    assert_eq!(router.astar(src, dst, 0.), Err(ResolutionMismatch));
+}
+
+#[test]
+fn reachable_uniform_cost() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let mut router = Router::new(|_| 1.).max_cost(2.);
+
+    let reachability = router.reachable([src]).expect("same res");
+    let mut result = reachability
+        .iter()
+        .map(|reached| reached.cell())
+        .collect::<Vec<_>>();
+    result.sort_unstable();
+    let mut expected = src.grid_disk::<Vec<_>>(2);
+    expected.sort_unstable();
+
+    assert_eq!(result, expected);
+    assert!(!reachability.is_truncated());
+    assert!(reachability.iter().all(|reached| reached.source() == src));
+    for reached in reachability.iter() {
+        let distance = src.grid_distance(reached.cell()).expect("distance");
+        assert_eq!(reached.cost(), f64::from(distance));
+    }
+    assert_eq!(reachability.cells_within(1.).count(), 7);
+}
+
+#[test]
+fn reachable_multi_source() {
+    let src1 = CellIndex::try_from(0x8a1fb46622dffff).expect("src1");
+    let src2 = CellIndex::try_from(0x8a1fb4644937fff).expect("src2");
+    let mut router = Router::new(|_| 1.).max_cost(3.);
+
+    let reachability = router.reachable([src1, src2]).expect("same res");
+
+    for reached in reachability.iter() {
+        let d1 = src1.grid_distance(reached.cell()).expect("distance");
+        let d2 = src2.grid_distance(reached.cell()).expect("distance");
+        assert_eq!(reached.cost(), f64::from(d1.min(d2)));
+        if d1 != d2 {
+            let expected = if d1 < d2 { src1 } else { src2 };
+            assert_eq!(reached.source(), expected);
+        }
+    }
+}
+
+#[test]
+fn reachable_budget() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let mut router = Router::new(|_| 1.).max_cells(10);
+
+    let reachability = router.reachable([src]).expect("same res");
+
+    assert_eq!(reachability.len(), 10);
+    assert!(reachability.is_truncated());
+    // Cheapest cells first.
+    assert_eq!(reachability.cells_within(1.).count(), 7);
+}
+
+#[test]
+fn reachable_resolution_mismatch() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let parent = src.parent(Resolution::Five).expect("parent");
+    let mut router = Router::new(|_| 1.);
+
+    assert_eq!(router.reachable([src, parent]), Err(ResolutionMismatch));
+    assert!(router.reachable([]).expect("empty").is_empty());
+}
@@ -0,0 +1,140 @@
+use h3o::{trajectory::Trajectory, CellIndex, LatLng, Resolution};
+
+#[test]
+fn single_point() {
+    let point = LatLng::new(48.864716, 2.349014).expect("point");
+    let mut trajectory = Trajectory::new(Resolution::Nine);
+    trajectory.push(point, 10.);
+    let visits = trajectory.visits();
+
+    assert_eq!(visits.len(), 1);
+    assert_eq!(visits[0].cell(), point.to_cell(Resolution::Nine));
+    assert_eq!(visits[0].entry(), 10.);
+    assert_eq!(visits[0].dwell(), 0.);
+}
+
+#[test]
+fn collapse_duplicates() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let center = LatLng::from(cell);
+    let points = (0..5).map(|i| {
+        let offset = f64::from(i) * 1e-7;
+        let point = LatLng::from_radians(
+            center.lat_radians() + offset,
+            center.lng_radians(),
+        )
+        .expect("point");
+        (point, f64::from(i) * 10.)
+    });
+    let mut trajectory = Trajectory::new(cell.resolution());
+    trajectory.extend(points);
+    let visits = trajectory.into_visits();
+
+    assert_eq!(visits.len(), 1);
+    assert_eq!(visits[0].cell(), cell);
+    assert_eq!(visits[0].entry(), 0.);
+    assert_eq!(visits[0].exit(), 40.);
+}
+
+#[test]
+fn gap_filling() {
+    let src = LatLng::new(48.864716, 2.349014).expect("src");
+    let dst = LatLng::new(48.858093, 2.294694).expect("dst");
+    let mut trajectory = Trajectory::new(Resolution::Ten);
+    trajectory.extend([(src, 100.), (dst, 1000.)]);
+    let visits = trajectory.visits();
+
+    assert!(visits.len() > 2);
+    assert_eq!(visits[0].cell(), src.to_cell(Resolution::Ten));
+    assert_eq!(
+        visits[visits.len() - 1].cell(),
+        dst.to_cell(Resolution::Ten)
+    );
+    assert_eq!(visits[0].entry(), 100.);
+    assert_eq!(visits[visits.len() - 1].exit(), 1000.);
+    for pair in visits.windows(2) {
+        assert_eq!(pair[0].cell().is_neighbor_with(pair[1].cell()), Ok(true));
+        assert_eq!(pair[0].exit(), pair[1].entry());
+        assert!(pair[0].dwell() >= 0.);
+    }
+    let dwell = visits.iter().map(|visit| visit.dwell()).sum::<f64>();
+    assert!((dwell - 900.).abs() < 1e-6);
+}
+
+#[test]
+fn dwell_interpolation() {
+    // Constant speed, from the center of a cell to the center of a neighbor.
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let dst = src
+        .grid_disk::<Vec<_>>(1)
+        .into_iter()
+        .find(|&cell| cell != src)
+        .expect("neighbor");
+    let mut trajectory = Trajectory::new(src.resolution());
+    trajectory.extend([(LatLng::from(src), 0.), (LatLng::from(dst), 60.)]);
+    let visits = trajectory.visits();
+
+    assert_eq!(visits.len(), 2);
+    assert!(
+        (visits[0].dwell() - 30.).abs() < 1.,
+        "{}",
+        visits[0].dwell()
+    );
+    assert!(
+        (visits[1].dwell() - 30.).abs() < 1.,
+        "{}",
+        visits[1].dwell()
+    );
+}
+
+#[test]
+fn revisit() {
+    let src = LatLng::new(48.864716, 2.349014).expect("src");
+    let dst = LatLng::new(48.858093, 2.294694).expect("dst");
+    let mut trajectory = Trajectory::new(Resolution::Seven);
+    trajectory.extend([(src, 0.), (dst, 10.), (src, 20.)]);
+    let visits = trajectory.visits();
+    let cells = visits.iter().map(|visit| visit.cell()).collect::<Vec<_>>();
+    let mut reversed = cells.clone();
+    reversed.reverse();
+
+    assert_eq!(cells, reversed);
+    assert_eq!(cells.len() % 2, 1);
+}
+
+#[test]
+fn entry_times_never_decrease() {
+    // Vertex to vertex at res 15, where the arc can't be traced exactly and
+    // the remaining cells are interpolated.
+    let points = [
+        (1.095806315903715, 2.717350090740481),
+        (1.0958063104186593, 2.7173506611286085),
+        (1.0958063829555889, 2.7173496878002394),
+        (1.0958066011823357, 2.717349988674033),
+    ];
+    let mut trajectory = Trajectory::new(Resolution::Fifteen);
+    for (i, (lat, lng)) in points.into_iter().enumerate() {
+        let point = LatLng::from_radians(lat, lng).expect("point");
+        trajectory.push(point, f64::from(u8::try_from(i).expect("i")) * 10.);
+    }
+    let visits = trajectory.visits();
+
+    assert!(visits.len() > 1);
+    assert!(visits
+        .windows(2)
+        .all(|pair| pair[0].entry() <= pair[1].entry()));
+    assert!(visits.iter().all(|visit| visit.dwell() >= 0.));
+}
+
+#[test]
+fn time_going_backward() {
+    let mut trajectory = Trajectory::new(Resolution::Nine);
+    trajectory.push(LatLng::new(48.864716, 2.349014).expect("src"), 100.);
+    trajectory.push(LatLng::new(48.858093, 2.294694).expect("dst"), 50.);
+    let visits = trajectory.visits();
+
+    assert!(visits.len() > 1);
+    assert!(visits
+        .iter()
+        .all(|visit| visit.entry() == 100. && visit.dwell() == 0.));
+}